@@ -0,0 +1,75 @@
+pub mod context;
+pub mod expect_with;
+pub mod inspect_ready;
+pub mod map_err_report;
+#[cfg(feature = "timer")]
+pub mod timeout;
+pub mod with_poll_count;
+
+impl<Fut> crate::FutureExt for Fut
+where
+    Fut: core::future::Future,
+{
+    fn map_err_report<T, E>(self) -> map_err_report::MapErrReport<Self>
+    where
+        Self: Sized,
+        Self: core::future::Future<Output = Result<T, E>>,
+        E: std::error::Error,
+    {
+        map_err_report::MapErrReport { future: self }
+    }
+
+    fn expect_with<M, F, T, E>(self, f: F) -> expect_with::ExpectWith<Self, F>
+    where
+        Self: Sized,
+        Self: core::future::Future<Output = Result<T, E>>,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+        E: std::error::Error,
+    {
+        expect_with::ExpectWith { future: self, f: Some(f) }
+    }
+
+    fn context<C, T, E>(self, context: C) -> context::WithContext<Self, C>
+    where
+        Self: Sized,
+        Self: core::future::Future<Output = Result<T, E>>,
+        C: std::fmt::Display,
+    {
+        context::WithContext {
+            future: self,
+            context: Some(context),
+        }
+    }
+
+    #[cfg(feature = "timer")]
+    fn timeout(self, duration: std::time::Duration) -> timeout::Timeout<Self, crate::timer::SystemClock>
+    where
+        Self: Sized,
+    {
+        use crate::timer::Clock as _;
+
+        let clock = crate::timer::SystemClock;
+        timeout::Timeout {
+            future: self,
+            duration,
+            sleep: clock.sleep_until(clock.now() + duration),
+        }
+    }
+
+    fn inspect_ready<F>(self, f: F) -> inspect_ready::InspectReady<Self, F>
+    where
+        Self: Sized,
+        Self: core::future::Future,
+        F: FnOnce(&<Self as core::future::Future>::Output),
+    {
+        inspect_ready::InspectReady { future: self, f: Some(f) }
+    }
+
+    fn with_poll_count(self) -> with_poll_count::WithPollCount<Self>
+    where
+        Self: Sized,
+    {
+        with_poll_count::WithPollCount { future: self, polls: 0 }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::report::ContextError;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that attaches a context message to the error of an inner future producing
+    /// `Result<T, E>`.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithContext<Fut, C> {
+        #[pin]
+        pub(crate) future: Fut,
+        pub(crate) context: Option<C>,
+    }
+}
+
+impl<Fut, C, T, E> Future for WithContext<Fut, C>
+where
+    Fut: Future<Output = Result<T, E>>,
+{
+    type Output = Result<T, ContextError<C, E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = ready!(this.future.poll(cx));
+        Poll::Ready(output.map_err(|error| ContextError {
+            context: this.context.take().expect("WithContext polled after completion"),
+            error,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FutureExt as _;
+    use derive_more::Display;
+    use derive_more::Error;
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock error occurred")]
+    struct MockError;
+
+    #[test]
+    fn test_context_ok() {
+        let output = futures::executor::block_on(async { Ok::<_, MockError>(42) }.context("loading config"));
+        assert_eq!(output.unwrap(), 42);
+    }
+
+    #[test]
+    fn test_context_err() {
+        let output = futures::executor::block_on(async { Err::<(), _>(MockError) }.context("loading config").map_err_report());
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            "loading config\n\nCaused by:\n      A mock error occurred"
+        );
+    }
+}
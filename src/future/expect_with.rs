@@ -0,0 +1,65 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that unwraps the `Ok` value of an inner future producing `Result<T, E>`,
+    /// panicking if it is an `Err` value.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ExpectWith<Fut, F> {
+        #[pin]
+        pub(crate) future: Fut,
+        pub(crate) f: Option<F>,
+    }
+}
+
+impl<Fut, F, M, T, E> Future for ExpectWith<Fut, F>
+where
+    Fut: Future<Output = Result<T, E>>,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+    E: std::error::Error,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        match ready!(this.future.poll(cx)) {
+            Ok(t) => Poll::Ready(t),
+            Err(error) => {
+                let msg = this.f.take().expect("ExpectWith polled after completion")();
+                panic!("{}: {:?}", msg.as_ref(), crate::report::Report { error })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FutureExt as _;
+    use derive_more::Display;
+    use derive_more::Error;
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock error occurred")]
+    struct MockError(MockSubError);
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock sub error occurred")]
+    struct MockSubError;
+
+    #[test]
+    fn test_expect_with_ok() {
+        let output = futures::executor::block_on(async { Ok::<_, MockError>(42) }.expect_with(|| -> String { unreachable!() }));
+        assert_eq!(output, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "Custom report: A mock error occurred\n\nCaused by:\n      A mock sub error occurred")]
+    fn test_expect_with_err() {
+        futures::executor::block_on(async { Err::<(), _>(MockError(MockSubError)) }.expect_with(|| "Custom report"));
+    }
+}
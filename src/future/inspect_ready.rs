@@ -0,0 +1,47 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that calls a function with a reference to the output of an inner future once
+    /// it is ready, passing the output through unchanged.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct InspectReady<Fut, F> {
+        #[pin]
+        pub(crate) future: Fut,
+        pub(crate) f: Option<F>,
+    }
+}
+
+impl<Fut, F> Future for InspectReady<Fut, F>
+where
+    Fut: Future,
+    F: FnOnce(&Fut::Output),
+{
+    type Output = Fut::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let output = ready!(this.future.poll(cx));
+        if let Some(f) = this.f.take() {
+            f(&output);
+        }
+        Poll::Ready(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FutureExt as _;
+
+    #[test]
+    fn test_inspect_ready() {
+        let mut seen = None;
+        let output = futures::executor::block_on(async { 42 }.inspect_ready(|output| seen = Some(*output)));
+        assert_eq!(output, 42);
+        assert_eq!(seen, Some(42));
+    }
+}
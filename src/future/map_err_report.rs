@@ -0,0 +1,53 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that wraps the error of an inner future producing `Result<T, E>` into a
+    /// [`Report`](crate::report::Report).
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct MapErrReport<Fut> {
+        #[pin]
+        pub(crate) future: Fut,
+    }
+}
+
+impl<Fut, T, E> Future for MapErrReport<Fut>
+where
+    Fut: Future<Output = Result<T, E>>,
+    E: std::error::Error,
+{
+    type Output = Result<T, crate::report::Report<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let output = ready!(self.project().future.poll(cx));
+        Poll::Ready(output.map_err(Into::into))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FutureExt as _;
+    use derive_more::Display;
+    use derive_more::Error;
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock error occurred")]
+    struct MockError(MockSubError);
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock sub error occurred")]
+    struct MockSubError;
+
+    #[test]
+    fn test_map_err_report() {
+        let output = futures::executor::block_on(async { Err::<(), _>(MockError(MockSubError)) }.map_err_report());
+        assert_eq!(
+            output.unwrap_err().to_string(),
+            "A mock error occurred\n\nCaused by:\n      A mock sub error occurred"
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::timer::Clock;
+use crate::timer::Elapsed;
+use crate::timer::SystemClock;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+pin_project_lite::pin_project! {
+    /// A future that resolves to an [`Elapsed`] error if an inner future does not complete
+    /// within a duration.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Timeout<Fut, C = SystemClock>
+    where
+        C: Clock,
+    {
+        #[pin]
+        pub(crate) future: Fut,
+        pub(crate) duration: Duration,
+        pub(crate) sleep: C::Sleep,
+    }
+}
+
+impl<Fut, C> Timeout<Fut, C>
+where
+    C: Clock,
+{
+    /// Uses the given clock to measure time, e.g. a [`MockClock`](crate::timer::MockClock) in
+    /// tests.
+    ///
+    /// The deadline restarts on the new clock.
+    pub fn with_clock<C2>(self, clock: C2) -> Timeout<Fut, C2>
+    where
+        C2: Clock,
+    {
+        Timeout {
+            future: self.future,
+            duration: self.duration,
+            sleep: clock.sleep_until(clock.now() + self.duration),
+        }
+    }
+}
+
+impl<Fut, C> Future for Timeout<Fut, C>
+where
+    Fut: Future,
+    C: Clock,
+{
+    type Output = Result<Fut::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        if let Poll::Ready(output) = this.future.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+
+        match Pin::new(this.sleep).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(Elapsed { duration: *this.duration })),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FutureExt as _;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use std::time::Duration;
+
+    #[test]
+    fn test_timeout_ready() {
+        let output = futures::executor::block_on(async { 42 }.timeout(Duration::from_millis(10)).with_clock(MockClock::new()));
+        assert_eq!(output, Ok(42));
+    }
+
+    #[test]
+    fn test_timeout_elapsed() {
+        let clock = MockClock::new();
+        let mut future = futures::future::pending::<()>()
+            .timeout(Duration::from_millis(10))
+            .with_clock(clock.clone());
+
+        assert_eq!((&mut future).now_or_never(), None);
+        clock.advance(Duration::from_millis(9));
+        assert_eq!((&mut future).now_or_never(), None);
+        clock.advance(Duration::from_millis(1));
+        let output = future.now_or_never().unwrap();
+        assert_eq!(output.unwrap_err().duration(), Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,63 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that counts how many times an inner future was polled, resolving to its output
+    /// together with the count.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct WithPollCount<Fut> {
+        #[pin]
+        pub(crate) future: Fut,
+        pub(crate) polls: usize,
+    }
+}
+
+impl<Fut> WithPollCount<Fut> {
+    /// Returns the number of times the inner future has been polled so far.
+    pub fn poll_count(&self) -> usize {
+        self.polls
+    }
+}
+
+impl<Fut> Future for WithPollCount<Fut>
+where
+    Fut: Future,
+{
+    type Output = (Fut::Output, usize);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        *this.polls += 1;
+        let output = ready!(this.future.poll(cx));
+        Poll::Ready((output, *this.polls))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FutureExt as _;
+    use futures::FutureExt as _;
+
+    #[test]
+    fn test_with_poll_count_ready() {
+        let output = futures::executor::block_on(async { 42 }.with_poll_count());
+        assert_eq!(output, (42, 1));
+    }
+
+    #[test]
+    fn test_with_poll_count_pending() {
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let mut future = rx.with_poll_count();
+
+        assert_eq!((&mut future).now_or_never(), None);
+        assert_eq!((&mut future).now_or_never(), None);
+        assert_eq!(future.poll_count(), 2);
+
+        tx.send(42).unwrap();
+        assert_eq!(future.now_or_never(), Some((Ok(42), 3)));
+    }
+}
@@ -1,7 +1,30 @@
+pub mod assert_all;
+pub mod assert_len;
+pub mod assert_sorted;
+pub mod assert_unique_by_key;
+pub mod chunk_by_key;
+pub mod chunks_array;
+pub mod dedup_by_key;
+pub mod dedup_with_count;
+pub mod exactly_one;
+pub mod format_with;
+pub mod join_kv;
+pub mod k_smallest;
+pub mod kmerge_by;
 pub mod map_into;
 pub mod map_opt;
 pub mod map_res;
 pub mod map_res_err;
+pub mod map_res_try_into;
+pub mod merge_sorted_by;
+pub mod min_max;
+pub mod stats;
+pub mod try_map_into;
+pub mod try_process;
+pub mod unique;
+pub mod windows_array;
+pub mod zip_eq;
+pub mod zip_longest;
 
 impl<I> crate::IteratorExt for I
 where
@@ -19,6 +42,18 @@ where
         }
     }
 
+    fn try_map_into<U>(self) -> try_map_into::TryMapInto<Self, U>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: TryInto<U>,
+    {
+        try_map_into::TryMapInto {
+            iter: self,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     fn map_opt<T, U, F>(self, f: F) -> map_opt::MapOpt<Self, F>
     where
         Self: Sized,
@@ -46,6 +81,19 @@ where
         map_res_err::MapResErr { iter: self, f }
     }
 
+    fn map_res_try_into<U, T, E>(self) -> map_res_try_into::MapResTryInto<Self, U>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        T: TryInto<U>,
+        E: From<T::Error>,
+    {
+        map_res_try_into::MapResTryInto {
+            iter: self,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
     fn join_as_strings(self, separator: &str) -> String
     where
         Self: Iterator,
@@ -54,4 +102,614 @@ where
         // TODO: Use intersperse when it becomes stable
         self.map(|x| x.to_string()).collect::<Vec<_>>().join(separator)
     }
+
+    fn join_kv<'a, K, V>(self, pair_sep: &'a str, kv_sep: &'a str) -> join_kv::JoinKv<'a, Self>
+    where
+        Self: Sized,
+        Self: Iterator<Item = (K, V)> + Clone,
+        K: std::fmt::Display,
+        V: std::fmt::Display,
+    {
+        join_kv::JoinKv {
+            iter: self,
+            pair_sep,
+            kv_sep,
+        }
+    }
+
+    fn format_with<F>(self, sep: &str, f: F) -> format_with::FormatWith<'_, Self, F>
+    where
+        Self: Sized,
+        Self: Iterator + Clone,
+        F: Fn(<Self as Iterator>::Item, &mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+    {
+        format_with::FormatWith { iter: self, f, sep }
+    }
+
+    fn collect_all<C, T, E>(self) -> Result<C, Vec<E>>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+    {
+        let mut errors = Vec::new();
+        let collection = self
+            .filter_map(|res| match res {
+                Ok(t) => Some(t),
+                Err(err) => {
+                    errors.push(err);
+                    None
+                }
+            })
+            .collect();
+
+        if errors.is_empty() { Ok(collection) } else { Err(errors) }
+    }
+
+    fn collect_all_report<C, T, E>(self) -> Result<C, crate::report::Report<crate::report::MultiError<E>>>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+        E: std::error::Error,
+    {
+        self.collect_all().map_err(|errors| crate::report::MultiError::from(errors).into())
+    }
+
+    fn partition_results<T, E>(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+    {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for res in self {
+            match res {
+                Ok(t) => oks.push(t),
+                Err(err) => errs.push(err),
+            }
+        }
+        (oks, errs)
+    }
+
+    fn try_process<T, E, F, R>(self, f: F) -> Result<R, E>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        F: FnOnce(try_process::ProcessResults<'_, Self, E>) -> R,
+    {
+        let mut error = Ok(());
+        let output = f(try_process::ProcessResults {
+            iter: self,
+            error: &mut error,
+        });
+        error.map(|()| output)
+    }
+
+    fn exactly_one(mut self) -> Result<<Self as Iterator>::Item, exactly_one::ExactlyOneError<Self>>
+    where
+        Self: Sized,
+        Self: Iterator,
+    {
+        match self.next() {
+            None => Err(exactly_one::ExactlyOneError {
+                first: None,
+                second: None,
+                iter: self,
+                expected: exactly_one::Expected::ExactlyOne,
+                failure: exactly_one::Failure::Empty,
+            }),
+            Some(first) => match self.next() {
+                None => Ok(first),
+                Some(second) => Err(exactly_one::ExactlyOneError {
+                    first: Some(first),
+                    second: Some(second),
+                    iter: self,
+                    expected: exactly_one::Expected::ExactlyOne,
+                    failure: exactly_one::Failure::MoreThanOne,
+                }),
+            },
+        }
+    }
+
+    fn at_most_one(mut self) -> Result<Option<<Self as Iterator>::Item>, exactly_one::ExactlyOneError<Self>>
+    where
+        Self: Sized,
+        Self: Iterator,
+    {
+        match self.next() {
+            None => Ok(None),
+            Some(first) => match self.next() {
+                None => Ok(Some(first)),
+                Some(second) => Err(exactly_one::ExactlyOneError {
+                    first: Some(first),
+                    second: Some(second),
+                    iter: self,
+                    expected: exactly_one::Expected::AtMostOne,
+                    failure: exactly_one::Failure::MoreThanOne,
+                }),
+            },
+        }
+    }
+
+    fn merge_sorted_by<J, F>(self, other: J, cmp: F) -> merge_sorted_by::MergeSortedBy<Self, J::IntoIter, F>
+    where
+        Self: Sized,
+        Self: Iterator,
+        J: IntoIterator<Item = <Self as Iterator>::Item>,
+        F: FnMut(&<Self as Iterator>::Item, &<Self as Iterator>::Item) -> std::cmp::Ordering,
+    {
+        merge_sorted_by::MergeSortedBy {
+            left: self.peekable(),
+            right: other.into_iter().peekable(),
+            cmp,
+        }
+    }
+
+    fn merge_sorted_res_by<J, F, T, E>(self, other: J, cmp: F) -> merge_sorted_by::MergeSortedBy<Self, J::IntoIter, crate::merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        J: IntoIterator<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        merge_sorted_by::MergeSortedBy {
+            left: self.peekable(),
+            right: other.into_iter().peekable(),
+            cmp: crate::merge::ErrorsFirst { cmp },
+        }
+    }
+
+    fn kmerge_by<F>(self, cmp: F) -> kmerge_by::KMergeBy<<<Self as Iterator>::Item as IntoIterator>::IntoIter, F>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: IntoIterator,
+        F: FnMut(&<<Self as Iterator>::Item as IntoIterator>::Item, &<<Self as Iterator>::Item as IntoIterator>::Item) -> std::cmp::Ordering,
+    {
+        kmerge_by::KMergeBy::new(self, cmp)
+    }
+
+    fn kmerge_res_by<F, T, E>(self, cmp: F) -> kmerge_by::KMergeBy<<<Self as Iterator>::Item as IntoIterator>::IntoIter, crate::merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: IntoIterator<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        kmerge_by::KMergeBy::new(self, crate::merge::ErrorsFirst { cmp })
+    }
+
+    fn windows_array<const N: usize>(self) -> windows_array::WindowsArray<Self, N>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Clone,
+    {
+        const { assert!(N > 0, "window size must be greater than zero") };
+        windows_array::WindowsArray {
+            iter: self,
+            buf: Vec::with_capacity(N),
+            start: 0,
+        }
+    }
+
+    fn chunks_array<const N: usize>(self) -> chunks_array::ChunksArray<Self, N>
+    where
+        Self: Sized,
+        Self: Iterator,
+    {
+        const { assert!(N > 0, "chunk size must be greater than zero") };
+        chunks_array::ChunksArray {
+            iter: self,
+            remainder: Vec::new(),
+            done: false,
+        }
+    }
+
+    fn chunk_by_key<K, F>(self, f: F) -> chunk_by_key::ChunkByKey<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: PartialEq,
+    {
+        chunk_by_key::ChunkByKey { iter: self, f, peeked: None }
+    }
+
+    fn lazy_chunk_by_key<K, F>(self, f: F) -> chunk_by_key::LazyChunkByKey<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: PartialEq,
+    {
+        chunk_by_key::LazyChunkByKey {
+            iter: self,
+            f,
+            peeked: None,
+            first: None,
+            current: None,
+        }
+    }
+
+    fn dedup_by_key<K, F>(self, f: F) -> dedup_by_key::DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: PartialEq,
+    {
+        dedup_by_key::DedupByKey { iter: self, f, last: None }
+    }
+
+    fn dedup_with_count(self) -> dedup_with_count::DedupWithCount<Self>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialEq,
+    {
+        dedup_with_count::DedupWithCount { iter: self, peeked: None }
+    }
+
+    fn zip_longest<J>(self, other: J) -> zip_longest::ZipLongest<Self, J::IntoIter>
+    where
+        Self: Sized,
+        Self: Iterator,
+        J: IntoIterator,
+    {
+        zip_longest::ZipLongest {
+            left: self.fuse(),
+            right: other.into_iter().fuse(),
+        }
+    }
+
+    fn zip_eq<J, M, F>(self, other: J, f: F) -> zip_eq::ZipEq<Self, J::IntoIter, F>
+    where
+        Self: Sized,
+        Self: Iterator,
+        J: IntoIterator,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+    {
+        zip_eq::ZipEq {
+            left: self,
+            right: other.into_iter(),
+            f: Some(f),
+            index: 0,
+        }
+    }
+
+    fn counts(self) -> std::collections::HashMap<<Self as Iterator>::Item, usize>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Eq + std::hash::Hash,
+    {
+        self.counts_by(|item| item)
+    }
+
+    fn counts_by<K, F>(self, mut f: F) -> std::collections::HashMap<K, usize>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(<Self as Iterator>::Item) -> K,
+        K: Eq + std::hash::Hash,
+    {
+        let mut counts = std::collections::HashMap::new();
+        for item in self {
+            *counts.entry(f(item)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn min_max(self) -> crate::MinMax<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialOrd,
+    {
+        min_max::min_max(self)
+    }
+
+    fn stats(self) -> crate::Stats
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: crate::ToF64,
+    {
+        let mut stats = crate::Stats::new();
+        for item in self {
+            stats.push(crate::ToF64::to_f64(item));
+        }
+        stats
+    }
+
+    fn sorted(self) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Ord,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort();
+        items.into_iter()
+    }
+
+    fn sorted_by_key<K, F>(self, f: F) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: Ord,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort_by_key(f);
+        items.into_iter()
+    }
+
+    fn sorted_unstable_by<F>(self, cmp: F) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item, &<Self as Iterator>::Item) -> std::cmp::Ordering,
+    {
+        let mut items: Vec<_> = self.collect();
+        items.sort_unstable_by(cmp);
+        items.into_iter()
+    }
+
+    fn unique(self) -> unique::Unique<Self>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Eq + std::hash::Hash + Clone,
+    {
+        unique::Unique {
+            iter: self,
+            seen: std::collections::HashSet::new(),
+        }
+    }
+
+    fn unique_by<K, F>(self, f: F) -> unique::UniqueBy<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: Eq + std::hash::Hash,
+    {
+        unique::UniqueBy {
+            iter: self,
+            f,
+            seen: std::collections::HashSet::new(),
+        }
+    }
+
+    fn k_smallest(self, k: usize) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Ord,
+    {
+        k_smallest::k_smallest(self, k).into_iter()
+    }
+
+    fn k_largest(self, k: usize) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Ord,
+    {
+        k_smallest::k_largest(self, k).into_iter()
+    }
+
+    fn assert_sorted<M, F>(self, f: F) -> assert_sorted::AssertSorted<Self, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialOrd + Clone + std::fmt::Debug,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>,
+    {
+        assert_sorted::AssertSorted {
+            iter: self,
+            f: Some(f),
+            prev: None,
+            index: 0,
+        }
+    }
+
+    fn assert_unique_by_key<K, Key, M, F>(self, key: K, f: F) -> assert_unique_by_key::AssertUniqueByKey<Self, K, Key, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        K: FnMut(&<Self as Iterator>::Item) -> Key,
+        Key: std::hash::Hash + Eq,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>,
+    {
+        assert_unique_by_key::AssertUniqueByKey {
+            iter: self,
+            key,
+            f: Some(f),
+            seen: std::collections::HashSet::new(),
+            index: 0,
+        }
+    }
+
+    fn assert_len<M, F>(self, len: usize, f: F) -> assert_len::AssertLen<Self, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+    {
+        assert_len::AssertLen {
+            iter: self,
+            f: Some(f),
+            len,
+            index: 0,
+        }
+    }
+
+    fn assert_all<P, M, F>(self, predicate: P, f: F) -> assert_all::AssertAll<Self, P, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        P: FnMut(&<Self as Iterator>::Item) -> bool,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>,
+    {
+        assert_all::AssertAll {
+            iter: self,
+            predicate,
+            f: Some(f),
+            index: 0,
+        }
+    }
+
+    fn debug_assert_sorted<M, F>(self, f: F) -> assert_sorted::AssertSorted<Self, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialOrd + Clone + std::fmt::Debug,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>,
+    {
+        assert_sorted::AssertSorted {
+            iter: self,
+            f: Some(f),
+            prev: None,
+            index: 0,
+        }
+    }
+
+    fn debug_assert_unique_by_key<K, Key, M, F>(self, key: K, f: F) -> assert_unique_by_key::AssertUniqueByKey<Self, K, Key, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        K: FnMut(&<Self as Iterator>::Item) -> Key,
+        Key: std::hash::Hash + Eq,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>,
+    {
+        assert_unique_by_key::AssertUniqueByKey {
+            iter: self,
+            key,
+            f: Some(f),
+            seen: std::collections::HashSet::new(),
+            index: 0,
+        }
+    }
+
+    fn debug_assert_len<M, F>(self, len: usize, f: F) -> assert_len::AssertLen<Self, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+    {
+        assert_len::AssertLen {
+            iter: self,
+            f: Some(f),
+            len,
+            index: 0,
+        }
+    }
+
+    fn debug_assert_all<P, M, F>(self, predicate: P, f: F) -> assert_all::AssertAll<Self, P, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        P: FnMut(&<Self as Iterator>::Item) -> bool,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>,
+    {
+        assert_all::AssertAll {
+            iter: self,
+            predicate,
+            f: Some(f),
+            index: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt as _;
+
+    /// Tests the [crate::IteratorExt::sorted], [crate::IteratorExt::sorted_by_key] and
+    /// [crate::IteratorExt::sorted_unstable_by] methods.
+    mod sorted {
+        use super::*;
+
+        #[test]
+        fn sorts_items() {
+            assert_eq!([3, 1, 2].into_iter().sorted().collect::<Vec<_>>(), vec![1, 2, 3]);
+            assert_eq!(std::iter::empty::<i32>().sorted().len(), 0);
+        }
+
+        #[test]
+        fn sort_by_key_is_stable() {
+            let pairs = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+            let sorted: Vec<_> = pairs.into_iter().sorted_by_key(|(key, _)| *key).collect();
+            assert_eq!(sorted, vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+        }
+
+        #[test]
+        fn sorts_unstable_by_comparison() {
+            let sorted: Vec<_> = ["bb", "a", "ccc"].into_iter().sorted_unstable_by(|a, b| b.len().cmp(&a.len())).collect();
+            assert_eq!(sorted, vec!["ccc", "bb", "a"]);
+        }
+    }
+
+    /// Tests the [crate::IteratorExt::collect_all] method.
+    mod collect_all {
+        use super::*;
+        use std::collections::HashSet;
+
+        #[test]
+        fn all_ok() {
+            let result: Result<Vec<i32>, Vec<&str>> = [Ok(1), Ok(2), Ok(3)].into_iter().collect_all();
+            assert_eq!(result, Ok(vec![1, 2, 3]));
+        }
+
+        #[test]
+        fn accumulates_every_error() {
+            let result: Result<HashSet<i32>, _> = [Err("a"), Ok(2), Err("b"), Ok(4)].into_iter().collect_all();
+            assert_eq!(result, Err(vec!["a", "b"]));
+        }
+
+        #[test]
+        fn consumes_whole_iterator() {
+            let mut seen = 0;
+            let _: Result<Vec<i32>, Vec<&str>> = [Err("a"), Ok(2), Ok(3)].into_iter().inspect(|_| seen += 1).collect_all();
+            assert_eq!(seen, 3);
+        }
+    }
+
+    /// Tests the [crate::IteratorExt::partition_results] method.
+    mod partition_results {
+        use super::*;
+
+        #[test]
+        fn mixed() {
+            let (oks, errs) = [Ok(1), Err("a"), Ok(3), Err("b")].into_iter().partition_results();
+            assert_eq!(oks, vec![1, 3]);
+            assert_eq!(errs, vec!["a", "b"]);
+        }
+
+        #[test]
+        fn empty() {
+            let (oks, errs): (Vec<i32>, Vec<&str>) = std::iter::empty().partition_results();
+            assert!(oks.is_empty());
+            assert!(errs.is_empty());
+        }
+    }
 }
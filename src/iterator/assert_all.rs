@@ -0,0 +1,84 @@
+/// An iterator adapter that passes the items of an inner iterator through unchanged, panicking
+/// if an item does not satisfy a predicate.
+///
+/// When `DEBUG` is `true`, the check is only performed when debug assertions are enabled.
+#[derive(Debug)]
+pub struct AssertAll<I, P, F, const DEBUG: bool> {
+    pub(crate) iter: I,
+    pub(crate) predicate: P,
+    pub(crate) f: Option<F>,
+    pub(crate) index: usize,
+}
+
+impl<I, P, F, M, const DEBUG: bool> Iterator for AssertAll<I, P, F, DEBUG>
+where
+    I: Iterator,
+    I::Item: std::fmt::Debug,
+    P: FnMut(&I::Item) -> bool,
+    F: FnOnce(&I::Item) -> M,
+    M: AsRef<str>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        if !DEBUG || cfg!(debug_assertions) {
+            if !(self.predicate)(&item)
+                && let Some(f) = self.f.take()
+            {
+                panic!("{} (index {}: {item:?})", f(&item).as_ref(), self.index);
+            }
+            self.index += 1;
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, P, F, M, const DEBUG: bool> std::iter::FusedIterator for AssertAll<I, P, F, DEBUG>
+where
+    I: Iterator + std::iter::FusedIterator,
+    I::Item: std::fmt::Debug,
+    P: FnMut(&I::Item) -> bool,
+    F: FnOnce(&I::Item) -> M,
+    M: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_assert_all_ok() {
+        let data = vec![2, 4, 6];
+        let result: Vec<_> = data.into_iter().assert_all(|x| x % 2 == 0, |_| "odd").collect();
+        assert_eq!(result, vec![2, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 is odd (index 1: 3)")]
+    fn test_assert_all_violation() {
+        let data = vec![2, 3, 4];
+        let _: Vec<_> = data.into_iter().assert_all(|x| x % 2 == 0, |x| format!("{x} is odd")).collect();
+    }
+
+    #[test]
+    fn test_assert_all_lazy() {
+        let data = vec![2, 3];
+        let result: Vec<_> = data.into_iter().assert_all(|x| x % 2 == 0, |_| "odd").take(1).collect();
+        assert_eq!(result, vec![2]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "odd (index 0: 1)"))]
+    fn test_debug_assert_all_violation() {
+        let data = vec![1];
+        let _: Vec<_> = data.into_iter().debug_assert_all(|x| x % 2 == 0, |_| "odd").collect();
+    }
+}
@@ -0,0 +1,102 @@
+/// An iterator adapter that passes the items of an inner iterator through unchanged, panicking
+/// if the inner iterator does not yield exactly the expected number of items.
+///
+/// When `DEBUG` is `true`, the check is only performed when debug assertions are enabled.
+#[derive(Debug)]
+pub struct AssertLen<I, F, const DEBUG: bool> {
+    pub(crate) iter: I,
+    pub(crate) f: Option<F>,
+    pub(crate) len: usize,
+    pub(crate) index: usize,
+}
+
+impl<I, F, M, const DEBUG: bool> Iterator for AssertLen<I, F, DEBUG>
+where
+    I: Iterator,
+    I::Item: std::fmt::Debug,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.iter.next();
+
+        if !DEBUG || cfg!(debug_assertions) {
+            match &next {
+                Some(item) if self.index >= self.len => {
+                    if let Some(f) = self.f.take() {
+                        panic!(
+                            "{} (expected {} items, found more: index {}: {item:?})",
+                            f().as_ref(),
+                            self.len,
+                            self.index
+                        );
+                    }
+                }
+                Some(_) => self.index += 1,
+                None if self.index < self.len => {
+                    if let Some(f) = self.f.take() {
+                        panic!("{} (expected {} items, found {})", f().as_ref(), self.len, self.index);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, M, const DEBUG: bool> std::iter::FusedIterator for AssertLen<I, F, DEBUG>
+where
+    I: Iterator + std::iter::FusedIterator,
+    I::Item: std::fmt::Debug,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_assert_len_ok() {
+        let data = vec![1, 2, 3];
+        let result: Vec<_> = data.into_iter().assert_len(3, || "wrong length").collect();
+        assert_eq!(result, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong length (expected 2 items, found more: index 2: 3)")]
+    fn test_assert_len_too_many() {
+        let data = vec![1, 2, 3];
+        let _: Vec<_> = data.into_iter().assert_len(2, || "wrong length").collect();
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong length (expected 4 items, found 3)")]
+    fn test_assert_len_too_few() {
+        let data = vec![1, 2, 3];
+        let _: Vec<_> = data.into_iter().assert_len(4, || "wrong length").collect();
+    }
+
+    #[test]
+    fn test_assert_len_partial_consumption() {
+        let data = vec![1, 2, 3];
+        let result: Vec<_> = data.into_iter().assert_len(3, || "wrong length").take(2).collect();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "wrong length (expected 0 items, found more: index 0: 1)"))]
+    fn test_debug_assert_len_violation() {
+        let data = vec![1];
+        let _: Vec<_> = data.into_iter().debug_assert_len(0, || "wrong length").collect();
+    }
+}
@@ -0,0 +1,80 @@
+/// An iterator adapter that passes the items of an inner iterator through unchanged, panicking
+/// if an item is smaller than the item before it.
+///
+/// When `DEBUG` is `true`, the check is only performed when debug assertions are enabled.
+#[derive(Debug)]
+pub struct AssertSorted<I, F, const DEBUG: bool>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) f: Option<F>,
+    pub(crate) prev: Option<I::Item>,
+    pub(crate) index: usize,
+}
+
+impl<I, F, M, const DEBUG: bool> Iterator for AssertSorted<I, F, DEBUG>
+where
+    I: Iterator,
+    I::Item: PartialOrd + Clone + std::fmt::Debug,
+    F: FnOnce(&I::Item) -> M,
+    M: AsRef<str>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        if !DEBUG || cfg!(debug_assertions) {
+            if let Some(prev) = &self.prev
+                && matches!(prev.partial_cmp(&item), Some(std::cmp::Ordering::Greater) | None)
+                && let Some(f) = self.f.take()
+            {
+                panic!("{} (index {}: {item:?})", f(&item).as_ref(), self.index);
+            }
+            self.prev = Some(item.clone());
+            self.index += 1;
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, M, const DEBUG: bool> std::iter::FusedIterator for AssertSorted<I, F, DEBUG>
+where
+    I: Iterator + std::iter::FusedIterator,
+    I::Item: PartialOrd + Clone + std::fmt::Debug,
+    F: FnOnce(&I::Item) -> M,
+    M: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_assert_sorted_ok() {
+        let data = vec![1, 2, 2, 5];
+        let result: Vec<_> = data.into_iter().assert_sorted(|_| "not sorted").collect();
+        assert_eq!(result, vec![1, 2, 2, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "item 1 out of order (index 2: 1)")]
+    fn test_assert_sorted_violation() {
+        let data = vec![1, 3, 1];
+        let _: Vec<_> = data.into_iter().assert_sorted(|item| format!("item {item} out of order")).collect();
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "not sorted (index 1: 0)"))]
+    fn test_debug_assert_sorted_violation() {
+        let data = vec![1, 0];
+        let _: Vec<_> = data.into_iter().debug_assert_sorted(|_| "not sorted").collect();
+    }
+}
@@ -0,0 +1,83 @@
+/// An iterator adapter that passes the items of an inner iterator through unchanged, panicking
+/// if the key of an item has been seen before.
+///
+/// When `DEBUG` is `true`, the check is only performed when debug assertions are enabled.
+#[derive(Debug)]
+pub struct AssertUniqueByKey<I, K, Key, F, const DEBUG: bool> {
+    pub(crate) iter: I,
+    pub(crate) key: K,
+    pub(crate) f: Option<F>,
+    pub(crate) seen: std::collections::HashSet<Key>,
+    pub(crate) index: usize,
+}
+
+impl<I, K, Key, F, M, const DEBUG: bool> Iterator for AssertUniqueByKey<I, K, Key, F, DEBUG>
+where
+    I: Iterator,
+    I::Item: std::fmt::Debug,
+    K: FnMut(&I::Item) -> Key,
+    Key: std::hash::Hash + Eq,
+    F: FnOnce(&I::Item) -> M,
+    M: AsRef<str>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        if !DEBUG || cfg!(debug_assertions) {
+            if !self.seen.insert((self.key)(&item))
+                && let Some(f) = self.f.take()
+            {
+                panic!("{} (index {}: {item:?})", f(&item).as_ref(), self.index);
+            }
+            self.index += 1;
+        }
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, K, Key, F, M, const DEBUG: bool> std::iter::FusedIterator for AssertUniqueByKey<I, K, Key, F, DEBUG>
+where
+    I: Iterator + std::iter::FusedIterator,
+    I::Item: std::fmt::Debug,
+    K: FnMut(&I::Item) -> Key,
+    Key: std::hash::Hash + Eq,
+    F: FnOnce(&I::Item) -> M,
+    M: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_assert_unique_by_key_ok() {
+        let data = vec![(1, "a"), (2, "a"), (3, "b")];
+        let result: Vec<_> = data.clone().into_iter().assert_unique_by_key(|(id, _)| *id, |_| "duplicate").collect();
+        assert_eq!(result, data);
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate id 1 (index 2: (1, \"c\"))")]
+    fn test_assert_unique_by_key_violation() {
+        let data = vec![(1, "a"), (2, "b"), (1, "c")];
+        let _: Vec<_> = data
+            .into_iter()
+            .assert_unique_by_key(|(id, _)| *id, |(id, _)| format!("duplicate id {id}"))
+            .collect();
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "duplicate (index 1: 1)"))]
+    fn test_debug_assert_unique_by_key_violation() {
+        let data = vec![1, 1];
+        let _: Vec<_> = data.into_iter().debug_assert_unique_by_key(|x| *x, |_| "duplicate").collect();
+    }
+}
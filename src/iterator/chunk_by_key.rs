@@ -0,0 +1,199 @@
+/// An iterator adapter that groups runs of consecutive items of an inner iterator with equal
+/// keys, yielding each key together with a `Vec` of its items.
+#[derive(Debug)]
+pub struct ChunkByKey<I, F, K>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) f: F,
+    pub(crate) peeked: Option<(K, I::Item)>,
+}
+
+impl<I, F, K> Iterator for ChunkByKey<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = pull(&mut self.iter, &mut self.f, &mut self.peeked)?;
+        let mut items = vec![first];
+        while let Some((next_key, item)) = pull(&mut self.iter, &mut self.f, &mut self.peeked) {
+            if next_key != key {
+                self.peeked = Some((next_key, item));
+                break;
+            }
+            items.push(item);
+        }
+        Some((key, items))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+        let (lower, upper) = self.iter.size_hint();
+        let lower = if lower > 0 || peeked > 0 { 1 } else { 0 };
+        (lower, upper.and_then(|upper| upper.checked_add(peeked)))
+    }
+}
+
+impl<I, F, K> std::iter::FusedIterator for ChunkByKey<I, F, K>
+where
+    I: std::iter::FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+}
+
+/// Groups runs of consecutive items of an inner iterator with equal keys, without allocating.
+///
+/// Call [`LazyChunkByKey::next_chunk`] to get the next run as a [`Chunk`], an iterator over
+/// the items of the run that pulls them from the inner iterator on demand.
+#[derive(Debug)]
+pub struct LazyChunkByKey<I, F, K>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) f: F,
+    pub(crate) peeked: Option<(K, I::Item)>,
+    pub(crate) first: Option<I::Item>,
+    pub(crate) current: Option<K>,
+}
+
+impl<I, F, K> LazyChunkByKey<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    /// Returns the next run of consecutive items with equal keys, or `None` if the inner iterator
+    /// is exhausted.
+    ///
+    /// The items of the previous run that were not consumed are skipped.
+    pub fn next_chunk(&mut self) -> Option<Chunk<'_, I, F, K>> {
+        self.first = None;
+        loop {
+            let (key, item) = pull(&mut self.iter, &mut self.f, &mut self.peeked)?;
+            if self.current.as_ref() != Some(&key) {
+                self.current = Some(key);
+                self.first = Some(item);
+                return Some(Chunk { parent: self });
+            }
+        }
+    }
+}
+
+/// A run of consecutive items with equal keys, returned by [`LazyChunkByKey::next_chunk`].
+pub struct Chunk<'a, I, F, K>
+where
+    I: Iterator,
+{
+    parent: &'a mut LazyChunkByKey<I, F, K>,
+}
+
+impl<I, F, K> Chunk<'_, I, F, K>
+where
+    I: Iterator,
+{
+    /// Returns the key shared by the items of the run.
+    pub fn key(&self) -> &K {
+        self.parent.current.as_ref().expect("a chunk always has a key")
+    }
+}
+
+impl<I, F, K> std::fmt::Debug for Chunk<'_, I, F, K>
+where
+    I: Iterator,
+    K: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Chunk").field("key", self.key()).finish_non_exhaustive()
+    }
+}
+
+impl<I, F, K> Iterator for Chunk<'_, I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parent = &mut *self.parent;
+        if let Some(item) = parent.first.take() {
+            return Some(item);
+        }
+
+        let (key, item) = pull(&mut parent.iter, &mut parent.f, &mut parent.peeked)?;
+        if parent.current.as_ref() == Some(&key) {
+            Some(item)
+        } else {
+            parent.peeked = Some((key, item));
+            None
+        }
+    }
+}
+
+/// Returns the peeked item if there is one, and otherwise the next item of the iterator, along
+/// with its key.
+fn pull<I, F, K>(iter: &mut I, f: &mut F, peeked: &mut Option<(K, I::Item)>) -> Option<(K, I::Item)>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+    peeked.take().or_else(|| {
+        let item = iter.next()?;
+        Some((f(&item), item))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_chunk_by_key_basic() {
+        let chunks: Vec<_> = [1, 3, 2, 4, 6, 5].into_iter().chunk_by_key(|x| x % 2).collect();
+        assert_eq!(chunks, vec![(1, vec![1, 3]), (0, vec![2, 4, 6]), (1, vec![5])]);
+    }
+
+    #[test]
+    fn test_chunk_by_key_empty() {
+        let chunks: Vec<(i32, Vec<i32>)> = std::iter::empty().chunk_by_key(|x| *x).collect();
+        assert!(chunks.is_empty());
+    }
+
+    #[test]
+    fn test_lazy_chunk_by_key() {
+        let mut chunks = [1, 3, 2, 4, 6, 5].into_iter().lazy_chunk_by_key(|x| x % 2);
+
+        let chunk = chunks.next_chunk().unwrap();
+        assert_eq!(*chunk.key(), 1);
+        assert_eq!(chunk.collect::<Vec<_>>(), vec![1, 3]);
+
+        // Only partially consumed, the rest is skipped.
+        let mut chunk = chunks.next_chunk().unwrap();
+        assert_eq!(*chunk.key(), 0);
+        assert_eq!(chunk.next(), Some(2));
+
+        let chunk = chunks.next_chunk().unwrap();
+        assert_eq!(*chunk.key(), 1);
+        assert_eq!(chunk.collect::<Vec<_>>(), vec![5]);
+
+        assert!(chunks.next_chunk().is_none());
+    }
+
+    #[test]
+    fn test_lazy_chunk_by_key_unconsumed() {
+        let mut chunks = [1, 1, 2, 2, 3].into_iter().lazy_chunk_by_key(|x| *x);
+        let mut keys = Vec::new();
+        while let Some(chunk) = chunks.next_chunk() {
+            keys.push(*chunk.key());
+        }
+        assert_eq!(keys, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,114 @@
+/// An iterator adapter that yields non-overlapping chunks of `N` consecutive items of an inner
+/// iterator, as arrays.
+///
+/// If the number of items is not a multiple of `N`, the items left over at the end are not
+/// yielded, but can be retrieved with [`ChunksArray::remainder`] once the iterator is
+/// exhausted.
+#[derive(Debug)]
+pub struct ChunksArray<I, const N: usize>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) remainder: Vec<I::Item>,
+    pub(crate) done: bool,
+}
+
+impl<I, const N: usize> ChunksArray<I, N>
+where
+    I: Iterator,
+{
+    /// Returns the items left over at the end that did not fill a whole chunk.
+    ///
+    /// This is empty until the iterator has returned `None`.
+    pub fn remainder(&self) -> &[I::Item] {
+        &self.remainder
+    }
+
+    /// Consumes the adapter, returning the items left over at the end that did not fill a whole
+    /// chunk.
+    pub fn into_remainder(self) -> Vec<I::Item> {
+        self.remainder
+    }
+}
+
+impl<I, const N: usize> Iterator for ChunksArray<I, N>
+where
+    I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let chunk: [Option<I::Item>; N] = std::array::from_fn(|_| {
+            if self.done {
+                return None;
+            }
+            let item = self.iter.next();
+            self.done = item.is_none();
+            item
+        });
+
+        if self.done {
+            self.remainder = chunk.into_iter().flatten().collect();
+            return None;
+        }
+
+        Some(chunk.map(|item| item.expect("every item is present unless the iterator is done")))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for ChunksArray<I, N> where I: ExactSizeIterator {}
+
+impl<I, const N: usize> std::iter::FusedIterator for ChunksArray<I, N> where I: Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_chunks_array_basic() {
+        let mut chunks = (1..8).chunks_array::<3>();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.next(), Some([1, 2, 3]));
+        assert_eq!(chunks.next(), Some([4, 5, 6]));
+        assert_eq!(chunks.remainder(), &[] as &[i32]);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), &[7]);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder(), vec![7]);
+    }
+
+    #[test]
+    fn test_chunks_array_exact() {
+        let mut chunks = (1..=4).chunks_array::<2>();
+        let collected: Vec<_> = chunks.by_ref().collect();
+        assert_eq!(collected, vec![[1, 2], [3, 4]]);
+        assert!(chunks.remainder().is_empty());
+    }
+
+    #[test]
+    fn test_chunks_array_stops_at_end() {
+        let mut calls = 0;
+        let mut chunks = std::iter::from_fn(|| {
+            calls += 1;
+            (calls <= 2).then_some(calls)
+        })
+        .chunks_array::<4>();
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder(), vec![1, 2]);
+        assert_eq!(calls, 3);
+    }
+}
@@ -0,0 +1,57 @@
+/// An iterator adapter that yields only the first item of each run of consecutive items with
+/// equal keys of an inner iterator.
+#[derive(Debug)]
+pub struct DedupByKey<I, F, K> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+    pub(crate) last: Option<K>,
+}
+
+impl<I, F, K> Iterator for DedupByKey<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let item = self.iter.next()?;
+            let key = (self.f)(&item);
+            if self.last.as_ref() != Some(&key) {
+                self.last = Some(key);
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // After a run, the next item may still have the same key as the last one yielded.
+        let lower = if self.last.is_none() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+impl<I, F, K> std::iter::FusedIterator for DedupByKey<I, F, K>
+where
+    I: std::iter::FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_dedup_by_key_basic() {
+        let deduped: Vec<_> = ["apple", "avocado", "banana", "blueberry", "apricot"]
+            .into_iter()
+            .dedup_by_key(|fruit| fruit.chars().next())
+            .collect();
+        assert_eq!(deduped, vec!["apple", "banana", "apricot"]);
+    }
+}
@@ -0,0 +1,62 @@
+/// An iterator adapter that yields each run of consecutive equal items of an inner iterator
+/// once, together with the length of the run.
+#[derive(Debug)]
+pub struct DedupWithCount<I>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) peeked: Option<I::Item>,
+}
+
+impl<I> Iterator for DedupWithCount<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.peeked.take().or_else(|| self.iter.next())?;
+        let mut count = 1;
+        for item in self.iter.by_ref() {
+            if item != first {
+                self.peeked = Some(item);
+                break;
+            }
+            count += 1;
+        }
+        Some((count, first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+        let (lower, upper) = self.iter.size_hint();
+        let lower = if lower > 0 || peeked > 0 { 1 } else { 0 };
+        (lower, upper.and_then(|upper| upper.checked_add(peeked)))
+    }
+}
+
+impl<I> std::iter::FusedIterator for DedupWithCount<I>
+where
+    I: std::iter::FusedIterator,
+    I::Item: PartialEq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_dedup_with_count_basic() {
+        let runs: Vec<_> = "aaabccdddd".chars().dedup_with_count().collect();
+        assert_eq!(runs, vec![(3, 'a'), (1, 'b'), (2, 'c'), (4, 'd')]);
+    }
+
+    #[test]
+    fn test_dedup_with_count_empty() {
+        let runs: Vec<(usize, char)> = "".chars().dedup_with_count().collect();
+        assert!(runs.is_empty());
+    }
+}
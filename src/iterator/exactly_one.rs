@@ -0,0 +1,181 @@
+use std::fmt;
+
+/// The error returned by [`IteratorExt::exactly_one`](crate::IteratorExt::exactly_one) and
+/// [`IteratorExt::at_most_one`](crate::IteratorExt::at_most_one) when the iterator did not
+/// contain the expected number of items.
+///
+/// The error is itself an iterator, yielding the items that were consumed while counting,
+/// followed by the remaining items of the inner iterator, so that no data is lost.
+pub struct ExactlyOneError<I>
+where
+    I: Iterator,
+{
+    pub(crate) first: Option<I::Item>,
+    pub(crate) second: Option<I::Item>,
+    pub(crate) iter: I,
+    pub(crate) expected: Expected,
+    pub(crate) failure: Failure,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Expected {
+    ExactlyOne,
+    AtMostOne,
+}
+
+/// Why the iterator did not contain the expected number of items, recorded up front because
+/// iterating the error drains the buffered items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Failure {
+    Empty,
+    MoreThanOne,
+}
+
+impl<I> ExactlyOneError<I>
+where
+    I: Iterator,
+{
+    /// Returns `true` if the iterator was empty.
+    pub fn was_empty(&self) -> bool {
+        self.failure == Failure::Empty
+    }
+
+    /// Returns `true` if the iterator contained more than one item.
+    pub fn had_more_than_one(&self) -> bool {
+        self.failure == Failure::MoreThanOne
+    }
+}
+
+impl<I> Iterator for ExactlyOneError<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.first.take().or_else(|| self.second.take()).or_else(|| self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = usize::from(self.first.is_some()) + usize::from(self.second.is_some());
+        let (lower, upper) = self.iter.size_hint();
+        (lower.saturating_add(buffered), upper.and_then(|upper| upper.checked_add(buffered)))
+    }
+}
+
+impl<I> fmt::Display for ExactlyOneError<I>
+where
+    I: Iterator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected = match self.expected {
+            Expected::ExactlyOne => "exactly one item",
+            Expected::AtMostOne => "at most one item",
+        };
+
+        match self.failure {
+            Failure::Empty => write!(f, "expected {expected}, but the iterator was empty"),
+            Failure::MoreThanOne => write!(f, "expected {expected}, but the iterator contained more than one"),
+        }
+    }
+}
+
+impl<I> fmt::Debug for ExactlyOneError<I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExactlyOneError")
+            .field("expected", &self.expected)
+            .field("failure", &self.failure)
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I> std::error::Error for ExactlyOneError<I>
+where
+    I: Iterator,
+    I::Item: fmt::Debug,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_exactly_one_ok() {
+        assert_eq!(std::iter::once(1).exactly_one().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_exactly_one_empty() {
+        let err = std::iter::empty::<i32>().exactly_one().unwrap_err();
+        assert!(err.was_empty());
+        assert!(!err.had_more_than_one());
+        assert_eq!(err.to_string(), "expected exactly one item, but the iterator was empty");
+        assert_eq!(err.count(), 0);
+    }
+
+    #[test]
+    fn test_exactly_one_too_many() {
+        let err = vec![1, 2, 3, 4].into_iter().exactly_one().unwrap_err();
+        assert!(err.had_more_than_one());
+        assert_eq!(err.to_string(), "expected exactly one item, but the iterator contained more than one");
+        assert_eq!(err.size_hint(), (4, Some(4)));
+        assert_eq!(err.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_exactly_one_kind_survives_iteration() {
+        let mut err = vec![1, 2].into_iter().exactly_one().unwrap_err();
+        assert_eq!(err.next(), Some(1));
+        assert_eq!(err.next(), Some(2));
+        assert!(err.had_more_than_one());
+        assert!(!err.was_empty());
+        assert_eq!(err.to_string(), "expected exactly one item, but the iterator contained more than one");
+    }
+
+    #[test]
+    fn test_at_most_one() {
+        assert_eq!(std::iter::empty::<i32>().at_most_one().unwrap(), None);
+        assert_eq!(std::iter::once(1).at_most_one().unwrap(), Some(1));
+
+        let err = vec![1, 2].into_iter().at_most_one().unwrap_err();
+        assert_eq!(err.to_string(), "expected at most one item, but the iterator contained more than one");
+        assert_eq!(err.collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "result")]
+    #[should_panic(
+        expected = "Query returned unexpected rows: ExactlyOneError { expected: ExactlyOne, failure: MoreThanOne, first: Some(1), second: Some(2), .. }"
+    )]
+    fn test_exactly_one_expect_with() {
+        use crate::ResultExt as _;
+        vec![1, 2].into_iter().exactly_one().expect_with(|| "Query returned unexpected rows");
+    }
+
+    #[test]
+    #[should_panic(expected = "ExactlyOneError { expected: AtMostOne, failure: MoreThanOne, first: Some(1), second: Some(2), .. }")]
+    fn test_at_most_one_unwrap() {
+        vec![1, 2, 3].into_iter().at_most_one().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "ExactlyOneError { expected: ExactlyOne, failure: Empty, first: None, second: None, .. }")]
+    fn test_exactly_one_unwrap_empty() {
+        std::iter::empty::<i32>().exactly_one().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "result")]
+    #[should_panic(expected = "No rows: expected exactly one item, but the iterator was empty")]
+    fn test_exactly_one_report() {
+        use crate::ResultExt as _;
+        std::iter::empty::<i32>().exactly_one().map_err_report().expect("No rows");
+    }
+}
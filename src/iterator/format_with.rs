@@ -0,0 +1,64 @@
+use std::fmt;
+
+/// A lazily formatted iterator with a custom per-item formatter, returned by
+/// [`IteratorExt::format_with`](crate::IteratorExt::format_with).
+pub struct FormatWith<'a, I, F> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+    pub(crate) sep: &'a str,
+}
+
+impl<I, F> fmt::Display for FormatWith<'_, I, F>
+where
+    I: Iterator + Clone,
+    F: Fn(I::Item, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.iter.clone().enumerate() {
+            if i > 0 {
+                f.write_str(self.sep)?;
+            }
+            (self.f)(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I, F> fmt::Debug for FormatWith<'_, I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormatWith").field("sep", &self.sep).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_format_with() {
+        let formatted = [1, 2, 3].into_iter().format_with(" | ", |n, f| write!(f, "#{n}"));
+        assert_eq!(formatted.to_string(), "#1 | #2 | #3");
+    }
+
+    #[test]
+    fn test_format_with_empty() {
+        let formatted = std::iter::empty::<i32>().format_with(", ", |n, f| write!(f, "{n}"));
+        assert_eq!(formatted.to_string(), "");
+    }
+
+    #[test]
+    fn test_format_with_nested() {
+        let rows = [vec![1, 2], vec![], vec![3]];
+        let formatted = rows
+            .iter()
+            .format_with("; ", |row, f| write!(f, "[{}]", row.iter().format_with(", ", |n, f| write!(f, "{n}"))));
+        assert_eq!(formatted.to_string(), "[1, 2]; []; [3]");
+    }
+
+    #[test]
+    fn test_format_with_formatted_twice() {
+        let formatted = [1, 2].into_iter().format_with(", ", |n, f| write!(f, "{n}"));
+        assert_eq!(formatted.to_string(), "1, 2");
+        assert_eq!(format!("{formatted}"), "1, 2");
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+/// A lazily formatted iterator of key/value pairs, returned by
+/// [`IteratorExt::join_kv`](crate::IteratorExt::join_kv).
+pub struct JoinKv<'a, I> {
+    pub(crate) iter: I,
+    pub(crate) pair_sep: &'a str,
+    pub(crate) kv_sep: &'a str,
+}
+
+impl<I, K, V> fmt::Display for JoinKv<'_, I>
+where
+    I: Iterator<Item = (K, V)> + Clone,
+    K: fmt::Display,
+    V: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.iter.clone().enumerate() {
+            if i > 0 {
+                f.write_str(self.pair_sep)?;
+            }
+            key.fmt(f)?;
+            f.write_str(self.kv_sep)?;
+            value.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<I> fmt::Debug for JoinKv<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinKv")
+            .field("pair_sep", &self.pair_sep)
+            .field("kv_sep", &self.kv_sep)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_join_kv() {
+        let map = BTreeMap::from([("b", 2), ("a", 1)]);
+        assert_eq!(map.iter().join_kv(", ", "=").to_string(), "a=1, b=2");
+    }
+
+    #[test]
+    fn test_join_kv_empty() {
+        let map = BTreeMap::<&str, i32>::new();
+        assert_eq!(map.iter().join_kv(", ", "=").to_string(), "");
+    }
+
+    #[test]
+    fn test_join_kv_respects_format_options() {
+        let pairs = [(1.0, 2.5), (3.25, 4.0)];
+        assert_eq!(format!("{:.1}", pairs.into_iter().join_kv("; ", ": ")), "1.0: 2.5; 3.2: 4.0");
+    }
+
+    #[test]
+    fn test_join_kv_formatted_twice() {
+        let joined = [("a", 1), ("b", 2)].into_iter().join_kv(", ", "=");
+        assert_eq!(joined.to_string(), "a=1, b=2");
+        assert_eq!(format!("{joined}"), "a=1, b=2");
+    }
+}
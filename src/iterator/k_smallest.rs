@@ -0,0 +1,68 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Returns the `k` smallest items in ascending order, keeping at most `k` items in a max-heap.
+pub(crate) fn k_smallest<I>(iter: I, k: usize) -> Vec<I::Item>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap = BinaryHeap::new();
+    for item in iter {
+        if heap.len() < k {
+            heap.push(item);
+        } else if let Some(mut largest) = heap.peek_mut()
+            && item < *largest
+        {
+            *largest = item;
+        }
+    }
+    heap.into_sorted_vec()
+}
+
+/// Returns the `k` largest items in descending order, keeping at most `k` items in a min-heap.
+pub(crate) fn k_largest<I>(iter: I, k: usize) -> Vec<I::Item>
+where
+    I: Iterator,
+    I::Item: Ord,
+{
+    // The smallest items of the reversed order are the largest ones, in descending order.
+    k_smallest(iter.map(Reverse), k).into_iter().map(|Reverse(item)| item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_k_smallest() {
+        let smallest: Vec<_> = [5, 1, 4, 1, 3, 9, 2].into_iter().k_smallest(3).collect();
+        assert_eq!(smallest, vec![1, 1, 2]);
+    }
+
+    #[test]
+    fn test_k_largest() {
+        let largest: Vec<_> = [5, 1, 4, 1, 3, 9, 2].into_iter().k_largest(3).collect();
+        assert_eq!(largest, vec![9, 5, 4]);
+    }
+
+    #[test]
+    fn test_k_smallest_fewer_items_than_k() {
+        let smallest: Vec<_> = [3, 1, 2].into_iter().k_smallest(10).collect();
+        assert_eq!(smallest, vec![1, 2, 3]);
+        let largest: Vec<_> = [3, 1, 2].into_iter().k_largest(10).collect();
+        assert_eq!(largest, vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_k_smallest_zero_does_not_consume() {
+        let mut pulled = 0;
+        assert_eq!((0..10).inspect(|_| pulled += 1).k_smallest(0).len(), 0);
+        assert_eq!((0..10).inspect(|_| pulled += 1).k_largest(0).len(), 0);
+        assert_eq!(pulled, 0);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::merge::HeadTail;
+use crate::merge::MergeHeap;
+use crate::merge::MergeOrder;
+
+/// An iterator adapter that merges any number of sorted iterators into one sorted iterator,
+/// using a binary heap of the next item of each iterator.
+///
+/// For equal items, the items of earlier iterators are yielded first.
+pub struct KMergeBy<I, F>
+where
+    I: Iterator,
+{
+    heap: MergeHeap<I::Item, I, F>,
+}
+
+impl<I, F> KMergeBy<I, F>
+where
+    I: Iterator,
+    F: MergeOrder<I::Item>,
+{
+    pub(crate) fn new(iters: impl IntoIterator<Item = impl IntoIterator<IntoIter = I>>, cmp: F) -> Self {
+        let mut heap = MergeHeap::new(cmp);
+        for (source, iter) in iters.into_iter().enumerate() {
+            let mut tail = iter.into_iter();
+            if let Some(head) = tail.next() {
+                heap.push(HeadTail { head, tail, source });
+            }
+        }
+        KMergeBy { heap }
+    }
+}
+
+impl<I, F> std::fmt::Debug for KMergeBy<I, F>
+where
+    I: Iterator + std::fmt::Debug,
+    I::Item: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KMergeBy").field("heap", &self.heap.entries()).finish_non_exhaustive()
+    }
+}
+
+impl<I, F> Iterator for KMergeBy<I, F>
+where
+    I: Iterator,
+    F: MergeOrder<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.heap.pop_head(Iterator::next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap.entries().iter().fold((0, Some(0)), |(lower, upper), entry| {
+            let (tail_lower, tail_upper) = entry.tail.size_hint();
+            let upper = upper
+                .zip(tail_upper)
+                .and_then(|(upper, tail_upper)| upper.checked_add(tail_upper)?.checked_add(1));
+            (lower.saturating_add(tail_lower).saturating_add(1), upper)
+        })
+    }
+}
+
+impl<I, F> std::iter::FusedIterator for KMergeBy<I, F>
+where
+    I: Iterator,
+    F: MergeOrder<I::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_kmerge_by_basic() {
+        let merged: Vec<_> = vec![vec![1, 4, 7], vec![2, 5, 8], vec![], vec![3, 6, 9]]
+            .into_iter()
+            .kmerge_by(|a, b| a.cmp(b))
+            .collect();
+        assert_eq!(merged, (1..=9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_kmerge_by_stable() {
+        let merged: Vec<_> = vec![vec![(1, 'a'), (2, 'a')], vec![(1, 'b')], vec![(1, 'c'), (2, 'c')]]
+            .into_iter()
+            .kmerge_by(|a, b| a.0.cmp(&b.0))
+            .collect();
+        assert_eq!(merged, vec![(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_kmerge_res_by() {
+        let merged: Vec<_> = vec![vec![Ok(1), Ok(4)], vec![Ok(2), Err("b"), Ok(5)], vec![Ok(3), Ok(6)]]
+            .into_iter()
+            .kmerge_res_by(|a, b| a.cmp(b))
+            .collect();
+        assert_eq!(merged, vec![Ok(1), Ok(2), Err("b"), Ok(3), Ok(4), Ok(5), Ok(6)]);
+    }
+
+    #[test]
+    fn test_kmerge_by_size_hint() {
+        let merged = vec![vec![1, 2], vec![3]].into_iter().kmerge_by(|a, b| a.cmp(b));
+        assert_eq!(merged.size_hint(), (3, Some(3)));
+    }
+}
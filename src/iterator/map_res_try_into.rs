@@ -0,0 +1,73 @@
+/// An iterator adapter that converts the `Ok` values of an inner iterator
+/// producing `Result<T, E>` into `U` using the `TryInto` trait.
+///
+/// Conversion errors are converted into `E` using the `From` trait.
+#[derive(Debug)]
+pub struct MapResTryInto<I, U> {
+    pub(crate) iter: I,
+    pub(crate) _marker: std::marker::PhantomData<U>,
+}
+
+impl<I, T, U, E> Iterator for MapResTryInto<I, U>
+where
+    I: Iterator<Item = Result<T, E>>,
+    T: TryInto<U>,
+    E: From<T::Error>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|res| res.and_then(|t| t.try_into().map_err(Into::into)))
+    }
+}
+
+impl<I, T, U, E> ExactSizeIterator for MapResTryInto<I, U>
+where
+    I: ExactSizeIterator<Item = Result<T, E>>,
+    T: TryInto<U>,
+    E: From<T::Error>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, U, E> std::iter::FusedIterator for MapResTryInto<I, U>
+where
+    I: Iterator<Item = Result<T, E>> + std::iter::FusedIterator,
+    T: TryInto<U>,
+    E: From<T::Error>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+    use derive_more::Display;
+    use derive_more::Error;
+    use derive_more::From;
+
+    #[derive(Debug, Display, Error, From, PartialEq)]
+    enum MockError {
+        #[display("Parse error")]
+        Parse,
+        #[display("Conversion error")]
+        Conversion(std::num::TryFromIntError),
+    }
+
+    #[test]
+    fn test_map_res_try_into_basic() {
+        let data = vec![Ok(1_i32), Err(MockError::Parse), Ok(-1)];
+        let result: Vec<Result<u8, MockError>> = data.into_iter().map_res_try_into().collect();
+        assert_eq!(result[0], Ok(1_u8));
+        assert_eq!(result[1], Err(MockError::Parse));
+        assert!(matches!(result[2], Err(MockError::Conversion(_))));
+    }
+
+    #[test]
+    fn test_map_res_try_into_len() {
+        let data: Vec<Result<u32, MockError>> = vec![Ok(10), Ok(20), Err(MockError::Parse)];
+        let iter = data.into_iter().map_res_try_into::<u8, _, _>();
+        assert_eq!(iter.len(), 3);
+    }
+}
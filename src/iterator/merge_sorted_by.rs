@@ -0,0 +1,96 @@
+use crate::merge::MergeOrder;
+use std::iter::Peekable;
+
+/// An iterator adapter that merges two sorted iterators into one sorted iterator.
+///
+/// For equal items, the items of the first iterator are yielded first.
+pub struct MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+{
+    pub(crate) left: Peekable<I>,
+    pub(crate) right: Peekable<J>,
+    pub(crate) cmp: F,
+}
+
+impl<I, J, F> std::fmt::Debug for MergeSortedBy<I, J, F>
+where
+    I: Iterator + std::fmt::Debug,
+    J: Iterator<Item = I::Item> + std::fmt::Debug,
+    I::Item: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MergeSortedBy")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, J, F> Iterator for MergeSortedBy<I, J, F>
+where
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: MergeOrder<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.left.peek(), self.right.peek()) {
+            (Some(left), Some(right)) if self.cmp.compare(right, left).is_lt() => self.right.next(),
+            (Some(_), _) => self.left.next(),
+            (None, _) => self.right.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (right_lower, right_upper) = self.right.size_hint();
+        let upper = left_upper.zip(right_upper).and_then(|(left, right)| left.checked_add(right));
+        (left_lower.saturating_add(right_lower), upper)
+    }
+}
+
+impl<I, J, F> std::iter::FusedIterator for MergeSortedBy<I, J, F>
+where
+    I: std::iter::FusedIterator,
+    J: std::iter::FusedIterator<Item = I::Item>,
+    F: MergeOrder<I::Item>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_merge_sorted_by_basic() {
+        let merged: Vec<_> = [1, 4, 5].into_iter().merge_sorted_by([2, 3, 6, 7], |a, b| a.cmp(b)).collect();
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_merge_sorted_by_stable() {
+        let merged: Vec<_> = [(1, 'a'), (2, 'a')]
+            .into_iter()
+            .merge_sorted_by([(1, 'b'), (2, 'b')], |a, b| a.0.cmp(&b.0))
+            .collect();
+        assert_eq!(merged, vec![(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+    }
+
+    #[test]
+    fn test_merge_sorted_res_by() {
+        let merged: Vec<_> = [Ok(1), Err("a"), Ok(5)]
+            .into_iter()
+            .merge_sorted_res_by([Ok(2), Ok(3), Ok(6)], |a, b| a.cmp(b))
+            .collect();
+        assert_eq!(merged, vec![Ok(1), Err("a"), Ok(2), Ok(3), Ok(5), Ok(6)]);
+    }
+
+    #[test]
+    fn test_merge_sorted_by_size_hint() {
+        let merged = [1, 3].into_iter().merge_sorted_by([2], |a, b| a.cmp(b));
+        assert_eq!(merged.size_hint(), (3, Some(3)));
+    }
+}
@@ -0,0 +1,86 @@
+/// The result of [`IteratorExt::min_max`](crate::IteratorExt::min_max).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MinMax<T> {
+    /// The iterator was empty.
+    Empty,
+    /// The iterator had exactly one item, which is both the minimum and the maximum.
+    One(T),
+    /// The iterator had more than one item. Holds the minimum and the maximum, in that order.
+    Pair(T, T),
+}
+
+impl<T> MinMax<T> {
+    /// Returns the minimum and the maximum, or `None` if the iterator was empty.
+    ///
+    /// If the iterator had exactly one item, it is cloned to be both.
+    pub fn into_option(self) -> Option<(T, T)>
+    where
+        T: Clone,
+    {
+        match self {
+            MinMax::Empty => None,
+            MinMax::One(item) => Some((item.clone(), item)),
+            MinMax::Pair(min, max) => Some((min, max)),
+        }
+    }
+}
+
+pub(crate) fn min_max<I>(mut iter: I) -> MinMax<I::Item>
+where
+    I: Iterator,
+    I::Item: PartialOrd,
+{
+    let Some(first) = iter.next() else {
+        return MinMax::Empty;
+    };
+    let Some(second) = iter.next() else {
+        return MinMax::One(first);
+    };
+
+    let (mut min, mut max) = if second < first { (second, first) } else { (first, second) };
+    for item in iter {
+        // Like `Iterator::min` and `Iterator::max`, keep the first minimum and the last maximum.
+        if item < min {
+            min = item;
+        } else if item >= max {
+            max = item;
+        }
+    }
+    MinMax::Pair(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MinMax;
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_min_max_basic() {
+        assert_eq!([3, 1, 4, 1, 5].into_iter().min_max(), MinMax::Pair(1, 5));
+        assert_eq!([7].into_iter().min_max(), MinMax::One(7));
+        assert_eq!(std::iter::empty::<i32>().min_max(), MinMax::Empty);
+    }
+
+    #[test]
+    fn test_min_max_first_min_last_max() {
+        let items = [(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (2, 'e')];
+        let min_max = items.into_iter().map(|(key, tag)| Keyed(key, tag)).min_max();
+        assert_eq!(min_max, MinMax::Pair(Keyed(0, 'b'), Keyed(2, 'e')));
+    }
+
+    #[test]
+    fn test_min_max_into_option() {
+        assert_eq!(MinMax::One(7).into_option(), Some((7, 7)));
+        assert_eq!(MinMax::<i32>::Empty.into_option(), None);
+    }
+
+    /// Compares only by the key, to tell equal items apart by the tag.
+    #[derive(Debug, PartialEq)]
+    struct Keyed(i32, char);
+
+    impl PartialOrd for Keyed {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            self.0.partial_cmp(&other.0)
+        }
+    }
+}
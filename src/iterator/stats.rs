@@ -0,0 +1,166 @@
+/// Summary statistics of numeric items, returned by
+/// [`IteratorExt::stats`](crate::IteratorExt::stats).
+///
+/// The variance is computed in a single pass with Welford's algorithm, which is numerically
+/// stable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    count: usize,
+    sum: f64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Stats {
+    pub(crate) fn new() -> Self {
+        Stats {
+            count: 0,
+            sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub(crate) fn push(&mut self, value: f64) {
+        self.count += 1;
+        self.sum += value;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Returns the number of items.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the sum of the items.
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Returns the arithmetic mean of the items, or `None` if there were none.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance of the items, or `None` if there were none.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance of the items, or `None` if there were fewer than two.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the population standard deviation of the items, or `None` if there were none.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the smallest item, or `None` if there were none.
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the largest item, or `None` if there were none.
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+/// A primitive numeric type that [`IteratorExt::stats`](crate::IteratorExt::stats) can
+/// convert to `f64`.
+///
+/// It is implemented for all primitive integer and floating point types, and is sealed. Integers
+/// that are too large to be represented exactly are rounded to the nearest `f64`.
+pub trait ToF64: sealed::Sealed {
+    /// Converts the value to `f64`.
+    fn to_f64(self) -> f64;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_to_f64 {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl ToF64 for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32);
+
+impl sealed::Sealed for f64 {}
+
+impl ToF64 for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_stats_basic() {
+        let stats = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().stats();
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.sum(), 40.0);
+        assert_eq!(stats.mean(), Some(5.0));
+        assert_eq!(stats.variance(), Some(4.0));
+        assert_eq!(stats.std_dev(), Some(2.0));
+        assert_eq!(stats.sample_variance(), Some(32.0 / 7.0));
+        assert_eq!(stats.min(), Some(2.0));
+        assert_eq!(stats.max(), Some(9.0));
+    }
+
+    #[test]
+    fn test_stats_empty() {
+        let stats = std::iter::empty::<f64>().stats();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.sum(), 0.0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.variance(), None);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+    }
+
+    #[test]
+    fn test_stats_single() {
+        let stats = [1.5f32].into_iter().stats();
+        assert_eq!(stats.mean(), Some(1.5));
+        assert_eq!(stats.variance(), Some(0.0));
+        assert_eq!(stats.sample_variance(), None);
+    }
+
+    #[test]
+    fn test_stats_u64() {
+        let stats = [1u64, 2, 3, u64::MAX].into_iter().stats();
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.min(), Some(1.0));
+        assert_eq!(stats.max(), Some(u64::MAX as f64));
+    }
+
+    #[test]
+    fn test_stats_stable_with_large_offset() {
+        let stats = [1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0].into_iter().stats();
+        assert_eq!(stats.variance(), Some(22.5));
+    }
+}
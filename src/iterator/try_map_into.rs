@@ -0,0 +1,64 @@
+/// An iterator adapter that converts the items of an inner iterator
+/// producing `T` into `Result<U, T::Error>` using the `TryInto` trait.
+#[derive(Debug)]
+pub struct TryMapInto<I, U> {
+    pub(crate) iter: I,
+    pub(crate) _marker: std::marker::PhantomData<U>,
+}
+
+impl<I, T, U> Iterator for TryMapInto<I, U>
+where
+    I: Iterator<Item = T>,
+    T: TryInto<U>,
+{
+    type Item = Result<U, T::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(TryInto::try_into)
+    }
+}
+
+impl<I, T, U> ExactSizeIterator for TryMapInto<I, U>
+where
+    I: ExactSizeIterator<Item = T>,
+    T: TryInto<U>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T, U> std::iter::FusedIterator for TryMapInto<I, U>
+where
+    I: Iterator<Item = T> + std::iter::FusedIterator,
+    T: TryInto<U>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_try_map_into_basic() {
+        let data = vec![1_i32, -1, 300];
+        let result: Vec<Result<u8, _>> = data.into_iter().try_map_into().collect();
+        assert_eq!(result[0], Ok(1_u8));
+        assert!(result[1].is_err());
+        assert!(result[2].is_err());
+    }
+
+    #[test]
+    fn test_try_map_into_turbofish() {
+        let data = vec![1_u32, 3_u32];
+        let result: Result<Vec<_>, _> = data.into_iter().try_map_into::<u8>().collect();
+        assert_eq!(result, Ok(vec![1_u8, 3_u8]));
+    }
+
+    #[test]
+    fn test_try_map_into_len() {
+        let data = vec![10_u32, 20, 30, 40];
+        let iter = data.into_iter().try_map_into::<u8>();
+        assert_eq!(iter.len(), 4);
+    }
+}
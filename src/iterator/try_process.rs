@@ -0,0 +1,90 @@
+/// An iterator adapter that yields the `Ok` values of an inner iterator producing
+/// `Result<T, E>`, stopping at the first `Err` and storing it.
+#[derive(Debug)]
+pub struct ProcessResults<'a, I, E> {
+    pub(crate) iter: I,
+    pub(crate) error: &'a mut Result<(), E>,
+}
+
+impl<I, T, E> Iterator for ProcessResults<'_, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_err() {
+            return None;
+        }
+
+        match self.iter.next() {
+            Some(Ok(t)) => Some(t),
+            Some(Err(err)) => {
+                *self.error = Err(err);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_err() {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I, T, E> std::iter::FusedIterator for ProcessResults<'_, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+    I: std::iter::FusedIterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_try_process_ok() {
+        let data: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+        let result = data.into_iter().try_process(|iter| iter.sum::<i32>());
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn test_try_process_first_err() {
+        let data = vec![Ok(1), Err("first"), Ok(3), Err("second")];
+        let result = data.into_iter().try_process(|iter| iter.max());
+        assert_eq!(result, Err("first"));
+    }
+
+    #[test]
+    fn test_try_process_stops_at_err() {
+        let mut pulled = 0;
+        let data = vec![Ok(1), Err("oops"), Ok(3)];
+        let result = data.into_iter().inspect(|_| pulled += 1).try_process(|iter| iter.collect::<Vec<_>>());
+        assert_eq!(result, Err("oops"));
+        assert_eq!(pulled, 2);
+    }
+
+    #[test]
+    fn test_try_process_fused_after_err() {
+        let data = vec![Err("oops"), Ok(2)];
+        let result = data.into_iter().try_process(|mut iter| {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            iter.next()
+        });
+        assert_eq!(result, Err("oops"));
+    }
+
+    #[test]
+    fn test_try_process_join_as_strings() {
+        let data: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2)];
+        let result = data.into_iter().try_process(|iter| iter.join_as_strings(", "));
+        assert_eq!(result.as_deref(), Ok("1, 2"));
+    }
+}
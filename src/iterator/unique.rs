@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// An iterator adapter that yields only the first occurrence of each distinct item of an inner
+/// iterator.
+#[derive(Debug)]
+pub struct Unique<I>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) seen: HashSet<I::Item>,
+}
+
+impl<I> Iterator for Unique<I>
+where
+    I: Iterator,
+    I::Item: Eq + Hash + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.by_ref().find(|item| !self.seen.contains(item)).inspect(|item| {
+            self.seen.insert(item.clone());
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // The first item is always new, any later one may have been seen before.
+        let lower = if self.seen.is_empty() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+impl<I> std::iter::FusedIterator for Unique<I>
+where
+    I: std::iter::FusedIterator,
+    I::Item: Eq + Hash + Clone,
+{
+}
+
+/// An iterator adapter that yields only the first item of an inner iterator for each distinct
+/// key.
+#[derive(Debug)]
+pub struct UniqueBy<I, F, K> {
+    pub(crate) iter: I,
+    pub(crate) f: F,
+    pub(crate) seen: HashSet<K>,
+}
+
+impl<I, F, K> Iterator for UniqueBy<I, F, K>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.by_ref().find(|item| self.seen.insert((self.f)(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // The first item is always new, any later one may have been seen before.
+        let lower = if self.seen.is_empty() { lower.min(1) } else { 0 };
+        (lower, upper)
+    }
+}
+
+impl<I, F, K> std::iter::FusedIterator for UniqueBy<I, F, K>
+where
+    I: std::iter::FusedIterator,
+    F: FnMut(&I::Item) -> K,
+    K: Eq + Hash,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_unique_keeps_first_occurrences() {
+        let unique: Vec<_> = [3, 1, 3, 2, 1, 4].into_iter().unique().collect();
+        assert_eq!(unique, vec![3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_unique_is_lazy() {
+        let mut pulled = 0;
+        let mut unique = [1, 1, 2, 3].into_iter().inspect(|_| pulled += 1).unique();
+        assert_eq!(unique.next(), Some(1));
+        assert_eq!(unique.next(), Some(2));
+        drop(unique);
+        assert_eq!(pulled, 3);
+    }
+
+    #[test]
+    fn test_unique_by() {
+        let words = ["apple", "Avocado", "banana", "apricot", "Blueberry", "cherry"];
+        let unique: Vec<_> = words.into_iter().unique_by(|word| word.to_lowercase().chars().next()).collect();
+        assert_eq!(unique, vec!["apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn test_unique_size_hint() {
+        let mut unique = [1, 1, 2].into_iter().unique();
+        assert_eq!(unique.size_hint(), (1, Some(3)));
+        unique.next();
+        assert_eq!(unique.size_hint(), (0, Some(2)));
+        assert_eq!(std::iter::empty::<i32>().unique().size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,92 @@
+/// An iterator adapter that yields overlapping windows of `N` consecutive items of an inner
+/// iterator, as arrays.
+///
+/// The last `N` items are kept in a ring buffer, so every window is a clone of the buffered
+/// items.
+#[derive(Debug)]
+pub struct WindowsArray<I, const N: usize>
+where
+    I: Iterator,
+{
+    pub(crate) iter: I,
+    pub(crate) buf: Vec<I::Item>,
+    pub(crate) start: usize,
+}
+
+impl<I, const N: usize> Iterator for WindowsArray<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buf.len() < N {
+            while self.buf.len() < N {
+                self.buf.push(self.iter.next()?);
+            }
+        } else {
+            self.buf[self.start] = self.iter.next()?;
+            self.start = (self.start + 1) % N;
+        }
+
+        Some(std::array::from_fn(|i| self.buf[(self.start + i) % N].clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.buf.len() == N {
+            return (lower, upper);
+        }
+
+        // The first window needs `N` items, and every item after that yields another window.
+        let missing = N - 1 - self.buf.len().min(N - 1);
+        (lower.saturating_sub(missing), upper.map(|upper| upper.saturating_sub(missing)))
+    }
+}
+
+impl<I, const N: usize> ExactSizeIterator for WindowsArray<I, N>
+where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{
+}
+
+impl<I, const N: usize> std::iter::FusedIterator for WindowsArray<I, N>
+where
+    I: std::iter::FusedIterator,
+    I::Item: Clone,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_windows_array_basic() {
+        let windows: Vec<_> = (1..=5).windows_array::<3>().collect();
+        assert_eq!(windows, vec![[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_windows_array_too_short() {
+        let mut windows = (1..3).windows_array::<3>();
+        assert_eq!(windows.len(), 0);
+        assert_eq!(windows.next(), None);
+    }
+
+    #[test]
+    fn test_windows_array_single() {
+        let windows: Vec<_> = vec!["a".to_string(), "b".to_string()].into_iter().windows_array::<1>().collect();
+        assert_eq!(windows, vec![["a".to_string()], ["b".to_string()]]);
+    }
+
+    #[test]
+    fn test_windows_array_len() {
+        let mut windows = (1..6).windows_array::<3>();
+        assert_eq!(windows.len(), 3);
+        windows.next();
+        assert_eq!(windows.len(), 2);
+    }
+}
@@ -0,0 +1,95 @@
+/// An iterator adapter that iterates two iterators simultaneously, panicking if they do not
+/// have the same length.
+#[derive(Debug)]
+pub struct ZipEq<I, J, F> {
+    pub(crate) left: I,
+    pub(crate) right: J,
+    pub(crate) f: Option<F>,
+    pub(crate) index: usize,
+}
+
+impl<I, J, F, M> Iterator for ZipEq<I, J, F>
+where
+    I: Iterator,
+    J: Iterator,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let side = match (self.left.next(), self.right.next()) {
+            (Some(a), Some(b)) => {
+                self.index += 1;
+                return Some((a, b));
+            }
+            (None, None) => return None,
+            (Some(_), None) => "left",
+            (None, Some(_)) => "right",
+        };
+
+        let f = self.f.take().expect("ZipEq polled after panicking");
+        panic!(
+            "{} (the {side} iterator is longer: both yielded {} items, then only the {side} iterator yielded another)",
+            f().as_ref(),
+            self.index
+        );
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (right_lower, right_upper) = self.right.size_hint();
+        let upper = match (left_upper, right_upper) {
+            (Some(left), Some(right)) => Some(left.min(right)),
+            (upper, None) | (None, upper) => upper,
+        };
+        (left_lower.min(right_lower), upper)
+    }
+}
+
+impl<I, J, F, M> ExactSizeIterator for ZipEq<I, J, F>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+{
+}
+
+impl<I, J, F, M> std::iter::FusedIterator for ZipEq<I, J, F>
+where
+    I: std::iter::FusedIterator,
+    J: std::iter::FusedIterator,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_zip_eq_ok() {
+        let zipped: Vec<_> = [1, 2].into_iter().zip_eq(["a", "b"], || "mismatch").collect();
+        assert_eq!(zipped, vec![(1, "a"), (2, "b")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "columns differ (the left iterator is longer: both yielded 2 items, then only the left iterator yielded another)")]
+    fn test_zip_eq_left_longer() {
+        let _: Vec<_> = [1, 2, 3].into_iter().zip_eq(["a", "b"], || "columns differ").collect();
+    }
+
+    #[test]
+    #[should_panic(expected = "columns differ (the right iterator is longer: both yielded 0 items, then only the right iterator yielded another)")]
+    fn test_zip_eq_right_longer() {
+        let _: Vec<_> = std::iter::empty::<i32>().zip_eq(["a"], || "columns differ").collect();
+    }
+
+    #[test]
+    fn test_zip_eq_message_is_lazy() {
+        let zipped: Vec<_> = [1].into_iter().zip_eq(["a"], || -> String { unreachable!() }).collect();
+        assert_eq!(zipped, vec![(1, "a")]);
+    }
+}
@@ -0,0 +1,203 @@
+/// An item of [`IteratorExt::zip_longest`](crate::IteratorExt::zip_longest), holding the items
+/// of both iterators, or of only one of them once the other is exhausted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<A, B> {
+    /// Both iterators yielded an item.
+    Both(A, B),
+    /// Only the left iterator yielded an item.
+    Left(A),
+    /// Only the right iterator yielded an item.
+    Right(B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Returns the left item, if there is one.
+    pub fn left(self) -> Option<A> {
+        match self {
+            EitherOrBoth::Both(a, _) | EitherOrBoth::Left(a) => Some(a),
+            EitherOrBoth::Right(_) => None,
+        }
+    }
+
+    /// Returns the right item, if there is one.
+    pub fn right(self) -> Option<B> {
+        match self {
+            EitherOrBoth::Both(_, b) | EitherOrBoth::Right(b) => Some(b),
+            EitherOrBoth::Left(_) => None,
+        }
+    }
+
+    /// Returns both items, if there are both.
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            EitherOrBoth::Both(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if there are both items.
+    pub fn is_both(&self) -> bool {
+        matches!(self, EitherOrBoth::Both(..))
+    }
+
+    /// Converts from `&EitherOrBoth<A, B>` to `EitherOrBoth<&A, &B>`.
+    pub fn as_ref(&self) -> EitherOrBoth<&A, &B> {
+        match self {
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(a, b),
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(a),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(b),
+        }
+    }
+
+    /// Transforms the left item, if there is one, using the given function `f`.
+    pub fn map_left<F, M>(self, f: F) -> EitherOrBoth<M, B>
+    where
+        F: FnOnce(A) -> M,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(f(a), b),
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(f(a)),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(b),
+        }
+    }
+
+    /// Transforms the right item, if there is one, using the given function `f`.
+    pub fn map_right<F, M>(self, f: F) -> EitherOrBoth<A, M>
+    where
+        F: FnOnce(B) -> M,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(a, f(b)),
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(a),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(f(b)),
+        }
+    }
+
+    /// Returns both items, using the given defaults for a missing item.
+    pub fn or(self, left: A, right: B) -> (A, B) {
+        match self {
+            EitherOrBoth::Both(a, b) => (a, b),
+            EitherOrBoth::Left(a) => (a, right),
+            EitherOrBoth::Right(b) => (left, b),
+        }
+    }
+
+    /// Returns both items, using [`Default::default`] for a missing item.
+    pub fn or_default(self) -> (A, B)
+    where
+        A: Default,
+        B: Default,
+    {
+        match self {
+            EitherOrBoth::Both(a, b) => (a, b),
+            EitherOrBoth::Left(a) => (a, B::default()),
+            EitherOrBoth::Right(b) => (A::default(), b),
+        }
+    }
+}
+
+/// An iterator adapter that iterates two iterators simultaneously until both are exhausted.
+///
+/// Both iterators are fused, so an exhausted iterator is not polled again while the other one
+/// carries on.
+#[derive(Debug)]
+pub struct ZipLongest<I, J> {
+    pub(crate) left: std::iter::Fuse<I>,
+    pub(crate) right: std::iter::Fuse<J>,
+}
+
+impl<I, J> Iterator for ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.left.next(), self.right.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (right_lower, right_upper) = self.right.size_hint();
+        (
+            left_lower.max(right_lower),
+            left_upper.zip(right_upper).map(|(left, right)| left.max(right)),
+        )
+    }
+}
+
+impl<I, J> ExactSizeIterator for ZipLongest<I, J>
+where
+    I: ExactSizeIterator,
+    J: ExactSizeIterator,
+{
+}
+
+impl<I, J> std::iter::FusedIterator for ZipLongest<I, J>
+where
+    I: Iterator,
+    J: Iterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EitherOrBoth;
+    use crate::IteratorExt;
+
+    #[test]
+    fn test_zip_longest_basic() {
+        let zipped: Vec<_> = [1, 2, 3].into_iter().zip_longest(["a"]).collect();
+        assert_eq!(zipped, vec![EitherOrBoth::Both(1, "a"), EitherOrBoth::Left(2), EitherOrBoth::Left(3)]);
+
+        let zipped: Vec<_> = [1].into_iter().zip_longest(["a", "b"]).collect();
+        assert_eq!(zipped, vec![EitherOrBoth::Both(1, "a"), EitherOrBoth::Right("b")]);
+    }
+
+    #[test]
+    fn test_zip_longest_len() {
+        let zipped = [1, 2, 3].into_iter().zip_longest(["a"]);
+        assert_eq!(zipped.len(), 3);
+    }
+
+    #[test]
+    fn test_zip_longest_fuses_both_iterators() {
+        // Yields `None` after every item, so it would resume after being exhausted if it were
+        // polled again.
+        let mut counter = 0;
+        let unfused = std::iter::from_fn(move || {
+            counter += 1;
+            (counter % 2 == 1).then_some(counter)
+        });
+
+        let zipped: Vec<_> = unfused.zip_longest(["a", "b", "c"]).collect();
+        assert_eq!(zipped, vec![EitherOrBoth::Both(1, "a"), EitherOrBoth::Right("b"), EitherOrBoth::Right("c")]);
+    }
+
+    #[test]
+    fn test_either_or_both_helpers() {
+        let both: EitherOrBoth<i32, &str> = EitherOrBoth::Both(1, "a");
+        let left: EitherOrBoth<i32, &str> = EitherOrBoth::Left(2);
+        let right: EitherOrBoth<i32, &str> = EitherOrBoth::Right("b");
+
+        assert_eq!(both.left(), Some(1));
+        assert_eq!(right.left(), None);
+        assert_eq!(both.right(), Some("a"));
+        assert_eq!(left.right(), None);
+        assert_eq!(both.both(), Some((1, "a")));
+        assert_eq!(left.both(), None);
+        assert_eq!(left.map_left(|x| x * 10), EitherOrBoth::Left(20));
+        assert_eq!(right.map_left(|x| x * 10), EitherOrBoth::Right("b"));
+        assert_eq!(right.map_right(str::len), EitherOrBoth::Right(1));
+        assert_eq!(left.or_default(), (2, ""));
+        assert_eq!(right.or(0, "z"), (0, "b"));
+        assert_eq!(both.as_ref(), EitherOrBoth::Both(&1, &"a"));
+        assert!(both.is_both());
+    }
+}
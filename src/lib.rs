@@ -4,6 +4,7 @@
 //! - `Result`
 //! - `Iterator`
 //! - `futures::Steam`
+//! - `Future`
 //!
 //! # Examples:
 //!
@@ -25,9 +26,15 @@
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+#[cfg(feature = "future")]
+mod future;
+
 #[cfg(feature = "iterator")]
 mod iterator;
 
+#[cfg(any(feature = "iterator", feature = "stream"))]
+mod merge;
+
 #[cfg(feature = "option")]
 mod option;
 
@@ -35,10 +42,33 @@ mod option;
 mod result;
 
 #[cfg(feature = "stream")]
-mod stream;
+pub mod stream;
+
+#[cfg(feature = "timer")]
+pub mod timer;
 
 pub mod report;
 
+#[cfg(feature = "stream")]
+pub mod testing;
+
+#[cfg(feature = "iterator")]
+pub use iterator::exactly_one::ExactlyOneError;
+#[cfg(feature = "iterator")]
+pub use iterator::min_max::MinMax;
+#[cfg(feature = "iterator")]
+pub use iterator::stats::Stats;
+#[cfg(feature = "iterator")]
+pub use iterator::stats::ToF64;
+#[cfg(feature = "iterator")]
+pub use iterator::zip_longest::EitherOrBoth;
+#[cfg(feature = "stream")]
+pub use stream::fanout::FanoutPolicy;
+#[cfg(feature = "stream")]
+pub use stream::take_until_signal::TakeUntilHandle;
+#[cfg(feature = "stream")]
+pub use stream::take_until_signal::TakeUntilStatus;
+
 /// [`std::option::Option`] extensions.
 ///
 /// Methods for the `Option` type.
@@ -154,6 +184,149 @@ pub trait ResultExt {
         M: AsRef<str>;
 }
 
+/// [`std::future::Future`] extensions.
+///
+/// Methods for futures, carrying the error handling patterns of [`ResultExt`] over to futures
+/// of `Result`, along with diagnostics.
+#[cfg(feature = "future")]
+pub trait FutureExt {
+    /// Maps the error of a future of `Result<T, E>` to [Report](crate::report::Report), a type
+    /// that includes the chain of source errors when displaying the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::FutureExt as _;
+    /// use derive_more::{Display, Error};
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Outer error")]
+    /// struct OuterError(InnerError);
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Inner error")]
+    /// struct InnerError;
+    ///
+    /// let output = futures::executor::block_on(async { Err::<(), _>(OuterError(InnerError)) }.map_err_report());
+    /// assert_eq!(output.unwrap_err().to_string(), "Outer error\n\nCaused by:\n      Inner error");
+    /// ```
+    fn map_err_report<T, E>(self) -> future::map_err_report::MapErrReport<Self>
+    where
+        Self: Sized,
+        Self: core::future::Future<Output = Result<T, E>>,
+        E: std::error::Error;
+
+    /// Unwraps the output of a future of `Result<T, E>`, yielding the content of an [`Ok`].
+    ///
+    /// The closure `f` is only evaluated if the output is an error. The panic message is the
+    /// message from `f`, followed by the error rendered as a [Report](crate::report::Report).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the output is an [`Err`].
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::FutureExt as _;
+    /// use derive_more::{Display, Error};
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Connection refused")]
+    /// struct ConnectionRefused;
+    ///
+    /// futures::executor::block_on(async { Err::<(), _>(ConnectionRefused) }.expect_with(|| "failed to connect"));
+    /// ```
+    fn expect_with<M, F, T, E>(self, f: F) -> future::expect_with::ExpectWith<Self, F>
+    where
+        Self: Sized,
+        Self: core::future::Future<Output = Result<T, E>>,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+        E: std::error::Error;
+
+    /// Attaches a context message to the error of a future of `Result<T, E>`, wrapping it in a
+    /// [`ContextError`](crate::report::ContextError) that has the original error as its source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::FutureExt as _;
+    /// use derive_more::{Display, Error};
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Connection refused")]
+    /// struct ConnectionRefused;
+    ///
+    /// let output = futures::executor::block_on(
+    ///     async { Err::<(), _>(ConnectionRefused) }
+    ///         .context("failed to fetch config")
+    ///         .map_err_report(),
+    /// );
+    /// assert_eq!(output.unwrap_err().to_string(), "failed to fetch config\n\nCaused by:\n      Connection refused");
+    /// ```
+    fn context<C, T, E>(self, context: C) -> future::context::WithContext<Self, C>
+    where
+        Self: Sized,
+        Self: core::future::Future<Output = Result<T, E>>,
+        C: std::fmt::Display;
+
+    /// Resolves to an [`Elapsed`](crate::timer::Elapsed) error if the future does not complete
+    /// within `duration`.
+    ///
+    /// The returned future measures time with the [system clock](crate::timer::SystemClock). Call
+    /// `with_clock` on it to use another [`Clock`](crate::timer::Clock), such as a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::FutureExt as _;
+    /// use std::time::Duration;
+    ///
+    /// let output = futures::executor::block_on(futures::future::pending::<()>().timeout(Duration::from_millis(10)));
+    /// assert!(output.is_err());
+    /// ```
+    #[cfg(feature = "timer")]
+    fn timeout(self, duration: std::time::Duration) -> future::timeout::Timeout<Self, timer::SystemClock>
+    where
+        Self: Sized;
+
+    /// Calls the function `f` with a reference to the output of the future once it is ready,
+    /// passing the output through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::FutureExt as _;
+    /// let output = futures::executor::block_on(async { 42 }.inspect_ready(|output| println!("done: {output}")));
+    /// assert_eq!(output, 42);
+    /// ```
+    fn inspect_ready<F>(self, f: F) -> future::inspect_ready::InspectReady<Self, F>
+    where
+        Self: Sized,
+        Self: core::future::Future,
+        F: FnOnce(&<Self as core::future::Future>::Output);
+
+    /// Counts how many times the future is polled, resolving to its output together with the
+    /// number of polls.
+    ///
+    /// The count so far is also available from the returned future through its `poll_count`
+    /// method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::FutureExt as _;
+    /// let (output, polls) = futures::executor::block_on(async { 42 }.with_poll_count());
+    /// assert_eq!(output, 42);
+    /// assert_eq!(polls, 1);
+    /// ```
+    fn with_poll_count(self) -> future::with_poll_count::WithPollCount<Self>
+    where
+        Self: Sized;
+}
+
 /// [`futures::Stream`] extensions.
 #[cfg(feature = "stream")]
 pub trait StreamExt {
@@ -197,97 +370,1765 @@ pub trait StreamExt {
         Self: Sized,
         Self: futures::Stream<Item = T>,
         F: core::future::Future<Output = T>;
-}
 
-/// [`std::iter::Iterator`] extensions.
-#[cfg(feature = "iterator")]
-pub trait IteratorExt {
-    /// Transforms the items in the iterator using the `Into` trait to convert
+    /// Chains a single ready item to the end of the stream if `condition` is `true`.
+    ///
+    /// If `condition` is `false`, the stream is left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let chained_stream = futures::stream::iter(vec![1, 2, 3]).chain_ready_if(true, 4);
+    /// let collected: Vec<_> = futures::executor::block_on_stream(chained_stream).collect();
+    /// assert_eq!(collected, vec![1, 2, 3, 4]);
+    ///
+    /// let chained_stream = futures::stream::iter(vec![1, 2, 3]).chain_ready_if(false, 4);
+    /// let collected: Vec<_> = futures::executor::block_on_stream(chained_stream).collect();
+    /// assert_eq!(collected, vec![1, 2, 3]);
+    /// ```
+    fn chain_ready_if<T>(self, condition: bool, item: T) -> futures::stream::Chain<Self, futures::stream::Iter<std::option::IntoIter<T>>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>;
+
+    /// Chains a single `Ok` item to the end of a stream of `Result<T, E>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("some error")]).chain_ok(3);
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(1), Err("some error"), Ok(3)]);
+    /// ```
+    fn chain_ok<T, E>(self, item: T) -> futures::stream::Chain<Self, futures::stream::Once<std::future::Ready<Result<T, E>>>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>;
+
+    /// Prepends a single ready item to the beginning of the stream.
+    ///
+    /// The item will be yielded before all items from the original stream. This is useful for
+    /// emitting an initial snapshot before a stream of live updates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let initial_stream = futures::stream::iter(vec![1, 2, 3]);
+    /// let prepended_stream = initial_stream.prepend_ready(0);
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+    /// assert_eq!(collected, vec![0, 1, 2, 3]);
+    /// ```
+    fn prepend_ready<T>(self, item: T) -> futures::stream::Chain<futures::stream::Once<std::future::Ready<T>>, Self>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>;
+
+    /// Prepends a single future to the beginning of the stream.
+    ///
+    /// When polled, the future will be awaited first, and its resulting item will be yielded
+    /// before all items from the original stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let initial_stream = futures::stream::iter(vec![1, 2, 3]);
+    /// let prepended_stream = initial_stream.prepend_future(Box::pin(async { 0 }));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+    /// assert_eq!(collected, vec![0, 1, 2, 3]);
+    /// ```
+    fn prepend_future<T, F>(self, fut: F) -> futures::stream::Chain<futures::stream::Once<F>, Self>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>,
+        F: core::future::Future<Output = T>;
+
+    /// Prepends a single ready item to the beginning of the stream if `condition` is `true`.
+    ///
+    /// If `condition` is `false`, the stream is left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let prepended_stream = futures::stream::iter(vec![1, 2, 3]).prepend_ready_if(true, 0);
+    /// let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+    /// assert_eq!(collected, vec![0, 1, 2, 3]);
+    ///
+    /// let prepended_stream = futures::stream::iter(vec![1, 2, 3]).prepend_ready_if(false, 0);
+    /// let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+    /// assert_eq!(collected, vec![1, 2, 3]);
+    /// ```
+    fn prepend_ready_if<T>(self, condition: bool, item: T) -> futures::stream::Chain<futures::stream::Iter<std::option::IntoIter<T>>, Self>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>;
+
+    /// Transforms the items in the stream using the `Into` trait to convert
     /// from `T` to `U`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use type_toppings::IteratorExt;
-    /// let data: Vec<_> = vec![1_u8, 3_u8]
-    ///     .into_iter()
-    ///     .map_into::<i32>()
-    ///     .collect();
-    /// assert_eq!(data, vec![1_i32, 3_i32]);
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![1_u8, 3_u8]).map_into::<i32>();
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![1_i32, 3_i32]);
     /// ```
-    fn map_into<U>(self) -> iterator::map_into::MapInto<Self, U>
+    fn map_into<U>(self) -> stream::map_into::MapInto<Self, U>
     where
         Self: Sized,
-        Self: Iterator,
-        <Self as Iterator>::Item: Into<U>;
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: Into<U>;
 
-    /// Transforms the `Some` values in iterators of `Option<T>` using the given function `f`.
+    /// Transforms the `Some` values in streams of `Option<T>` using the given function `f`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use type_toppings::IteratorExt;
-    /// let data: Vec<_> = vec![Some(1), None, Some(3)].into_iter().map_opt(|x| x * 2).collect();
-    /// assert_eq!(data, vec![Some(2), None, Some(6)]);
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Some(1), None, Some(3)]).map_opt(|x| x * 2);
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Some(2), None, Some(6)]);
     /// ```
-    fn map_opt<T, U, F>(self, f: F) -> iterator::map_opt::MapOpt<Self, F>
+    fn map_opt<T, U, F>(self, f: F) -> stream::map_opt::MapOpt<Self, F>
     where
         Self: Sized,
-        Self: Iterator<Item = Option<T>>,
+        Self: futures::Stream<Item = Option<T>>,
         F: FnMut(T) -> U;
 
-    /// Transforms the `Ok` values in iterators of `Result<T, E>` using the given function `f`.
+    /// Transforms the `Ok` values in streams of `Result<T, E>` using the given function `f`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use type_toppings::IteratorExt;
-    /// let data = [Ok(1), Err("some error"), Ok(3)]
-    ///     .into_iter()
-    ///     .map_res(|x| x * 2)
-    ///     .collect::<Vec<_>>();
-    /// assert_eq!(data, vec![Ok(2), Err("some error"), Ok(6)]);
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("some error"), Ok(3)]).map_res(|x| x * 2);
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(2), Err("some error"), Ok(6)]);
     /// ```
-    fn map_res<F, T, U, E>(self, f: F) -> iterator::map_res::MapRes<Self, F>
+    fn map_res<F, T, U, E>(self, f: F) -> stream::map_res::MapRes<Self, F>
     where
         Self: Sized,
-        Self: Iterator<Item = Result<T, E>>,
+        Self: futures::Stream<Item = Result<T, E>>,
         F: FnMut(T) -> U;
 
-    /// Transforms the `Err` values in iterators of `Result<T, E>` using the given function `f`.
+    /// Transforms the `Err` values in streams of `Result<T, E>` using the given function `f`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use type_toppings::IteratorExt;
-    /// let data = [Ok(1), Err("unexpected thing happened"), Ok(3)]
-    ///     .into_iter()
-    ///     .map_res_err(|err| format!("Oh no: {err}"))
-    ///     .collect::<Vec<_>>();
-    /// assert_eq!(data, vec![Ok(1), Err("Oh no: unexpected thing happened".to_string()), Ok(3)]);
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("unexpected thing happened"), Ok(3)])
+    ///     .map_res_err(|err| format!("Oh no: {err}"));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(1), Err("Oh no: unexpected thing happened".to_string()), Ok(3)]);
     /// ```
-    fn map_res_err<F, T, U, E>(self, f: F) -> iterator::map_res_err::MapResErr<Self, F>
+    fn map_res_err<F, T, U, E>(self, f: F) -> stream::map_res_err::MapResErr<Self, F>
     where
         Self: Sized,
-        Self: Iterator<Item = Result<T, E>>,
+        Self: futures::Stream<Item = Result<T, E>>,
         F: FnMut(E) -> U;
 
-    /// Converts each element of the iterator to a string and joins them into a single string, separated by the specified separator.
+    /// Wraps the `Err` values in streams of `Result<T, E>` into a [`report::Report`], like
+    /// [`ResultExt::map_err_report`] does for a single `Result`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use type_toppings::IteratorExt as _;
-    /// let numbers = vec![1, 2, 3];
-    /// let sequence = numbers.into_iter().join_as_strings(", ");
-    /// assert_eq!(sequence, "1, 2, 3");
+    /// # use type_toppings::StreamExt as _;
+    /// use derive_more::{Display, Error};
     ///
-    /// let words = vec!["hello", "world"];
-    /// let sentence = words.into_iter().join_as_strings(" - ");
-    /// assert_eq!(sentence, "hello - world");
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Connection lost")]
+    /// struct ConnectionLost(Timeout);
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Timed out")]
+    /// struct Timeout;
+    ///
+    /// let stream = futures::stream::iter(vec![Ok(1), Err(ConnectionLost(Timeout))]).map_err_report();
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected[1].as_ref().unwrap_err().to_string(), "Connection lost\n\nCaused by:\n      Timed out");
     /// ```
-    fn join_as_strings(self, separator: &str) -> String
+    fn map_err_report<T, E>(self) -> stream::map_res_err::MapResErr<Self, fn(E) -> crate::report::Report<E>>
     where
-        Self: Iterator,
-        <Self as Iterator>::Item: ToString;
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        E: std::error::Error;
+
+    /// Calls the function `f` with a reference to each `Err` value in streams of
+    /// `Result<T, E>`, passing every item through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let mut errors = 0;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("some error"), Ok(3)]).inspect_err(|_| errors += 1);
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(1), Err("some error"), Ok(3)]);
+    /// assert_eq!(errors, 1);
+    /// ```
+    fn inspect_err<F, T, E>(self, f: F) -> stream::inspect_err::InspectErr<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(&E);
+
+    /// Unwraps the `Ok` values in streams of `Result<T, E>`, panicking on the first `Err` value.
+    ///
+    /// Like [`ResultExt::expect_with`], the closure `f` is only evaluated if an `Err` value is
+    /// encountered. The panic message is the message from `f`, followed by the error rendered
+    /// as a [`report::Report`], including its chain of source errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the stream yields an `Err` value.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::StreamExt as _;
+    /// use derive_more::{Display, Error};
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Connection lost")]
+    /// struct ConnectionLost;
+    ///
+    /// let stream = futures::stream::iter(vec![Ok(1), Err(ConnectionLost)]).expect_with(|| "event stream failed");
+    ///
+    /// // Panics with "event stream failed: Connection lost"
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// ```
+    fn expect_with<M, F, T, E>(self, f: F) -> stream::expect_with::ExpectWith<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+        E: std::error::Error;
+
+    /// Transforms the `Ok` values in streams of `Result<T, E>` using the given async function
+    /// `f`, running up to `limit` futures concurrently.
+    ///
+    /// The order of the items is preserved, and `Err` values are passed through untouched in
+    /// their original position. At most `limit` items are buffered at a time, including `Err`
+    /// values waiting for their turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("some error"), Ok(3)])
+    ///     .map_res_concurrent(2, |x| async move { x * 2 });
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(Box::pin(stream)).collect();
+    /// assert_eq!(collected, vec![Ok(2), Err("some error"), Ok(6)]);
+    /// ```
+    fn map_res_concurrent<F, Fut, T, U, E>(self, limit: usize, f: F) -> stream::map_res_concurrent::MapResConcurrent<Self, F, Fut, U, E>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(T) -> Fut,
+        Fut: core::future::Future<Output = U>;
+
+    /// Yields the items of the stream until the `signal` future resolves, e.g. on shutdown.
+    ///
+    /// The signal is checked before every item, so the stream ends as soon as the signal has
+    /// resolved, even if the inner stream has more items ready. The output of the signal is
+    /// discarded. Call `handle` on the returned stream to get a [`TakeUntilHandle`] that reports
+    /// whether the stream was interrupted by the signal or ended on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let (shutdown_tx, shutdown_rx) = futures::channel::oneshot::channel::<()>();
+    /// let stream = futures::stream::iter(vec![1, 2, 3]).take_until_signal(shutdown_rx);
+    /// let handle = stream.handle();
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![1, 2, 3]);
+    /// assert!(!handle.was_interrupted());
+    /// # drop(shutdown_tx);
+    /// ```
+    fn take_until_signal<Fut>(
+        self,
+        signal: Fut,
+    ) -> stream::take_until_signal::TakeUntilSignal<Self, stream::take_until_signal::DiscardOutput<Fut, <Self as futures::Stream>::Item>>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        Fut: core::future::Future;
+
+    /// Yields the items of the stream until the `signal` future resolves, then yields the
+    /// output of the signal as the final item if it is `Some`.
+    ///
+    /// This is like [`StreamExt::take_until_signal`], with the option of emitting a last item,
+    /// as [`StreamExt::chain_future`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let (shutdown_tx, shutdown_rx) = futures::channel::oneshot::channel::<()>();
+    /// let stream = futures::stream::pending().take_until_signal_then(async {
+    ///     shutdown_rx.await.ok();
+    ///     Some("shutting down")
+    /// });
+    /// let handle = stream.handle();
+    /// shutdown_tx.send(()).unwrap();
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(Box::pin(stream)).collect();
+    /// assert_eq!(collected, vec!["shutting down"]);
+    /// assert!(handle.was_interrupted());
+    /// ```
+    fn take_until_signal_then<Fut>(self, signal: Fut) -> stream::take_until_signal::TakeUntilSignal<Self, Fut>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        Fut: core::future::Future<Output = Option<<Self as futures::Stream>::Item>>;
+
+    /// Splits the stream into `consumers` streams that each receive a clone of every item.
+    ///
+    /// Each consumer can be polled independently and buffers up to `capacity` items that it has
+    /// not consumed yet. The `policy` decides what happens when the buffer of a slow consumer is
+    /// full: with [`FanoutPolicy::Backpressure`], no more items are pulled from the stream until it
+    /// catches up, and with [`FanoutPolicy::DropOldest`], its oldest buffered item is dropped,
+    /// which it can detect with the `lagged` method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use type_toppings::FanoutPolicy;
+    ///
+    /// let mut consumers = futures::stream::iter(vec![1, 2, 3]).fanout(2, 8, FanoutPolicy::Backpressure);
+    /// let b = consumers.pop().unwrap();
+    /// let a = consumers.pop().unwrap();
+    ///
+    /// assert_eq!(futures::executor::block_on_stream(a).collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(futures::executor::block_on_stream(b).collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    fn fanout(self, consumers: usize, capacity: usize, policy: FanoutPolicy) -> Vec<stream::fanout::Fanout<Self>>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: Clone;
+
+    /// Turns the stream into a consumer that can be cloned to add more consumers, each of which
+    /// receives a clone of every item pulled from the stream after it was created.
+    ///
+    /// See [`StreamExt::fanout`] for how `capacity` and `policy` apply to every consumer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use type_toppings::FanoutPolicy;
+    ///
+    /// let a = futures::stream::iter(vec![1, 2, 3]).broadcast(8, FanoutPolicy::Backpressure);
+    /// let b = a.clone();
+    ///
+    /// assert_eq!(futures::executor::block_on_stream(a).collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(futures::executor::block_on_stream(b).collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    fn broadcast(self, capacity: usize, policy: FanoutPolicy) -> stream::fanout::Fanout<Self>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: Clone;
+
+    /// Converts each item of the stream to a string and joins them into a single string, separated by the specified separator.
+    ///
+    /// The returned future resolves once the stream has ended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![1, 2, 3]);
+    /// let sequence = futures::executor::block_on(stream.join_as_strings(", "));
+    /// assert_eq!(sequence, "1, 2, 3");
+    /// ```
+    fn join_as_strings(self, separator: &str) -> stream::join_as_strings::JoinAsStrings<Self>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: ToString;
+
+    /// Collects the `Ok` values of a stream of `Result<T, E>` into a collection, accumulating
+    /// every error rather than stopping at the first one.
+    ///
+    /// The returned future always drains the whole stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("a"), Ok(3), Err("b")]);
+    /// let result: Result<Vec<i32>, _> = futures::executor::block_on(stream.collect_all());
+    /// assert_eq!(result, Err(vec!["a", "b"]));
+    /// ```
+    fn collect_all<C, T, E>(self) -> stream::collect_all::CollectAll<Self, C, E>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        C: Default + Extend<T>;
+
+    /// Partitions a stream of `Result<T, E>` into the `Ok` values and the `Err` values.
+    ///
+    /// The returned future always drains the whole stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![Ok(1), Err("a"), Ok(3)]);
+    /// let (oks, errs) = futures::executor::block_on(stream.partition_results());
+    /// assert_eq!(oks, vec![1, 3]);
+    /// assert_eq!(errs, vec!["a"]);
+    /// ```
+    fn partition_results<T, E>(self) -> stream::partition_results::PartitionResults<Self, T, E>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>;
+
+    /// Merges two sorted streams into one sorted stream, comparing items with `cmp`.
+    ///
+    /// An item is only emitted once the next item of both streams is known, or one of them has
+    /// ended. The merge is stable: for equal items, the items of `self` are emitted before the
+    /// items of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let stream = futures::stream::iter(vec![1, 4, 5]).merge_sorted_by(futures::stream::iter(vec![2, 3, 6]), |a, b| a.cmp(b));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn merge_sorted_by<S2, F>(self, other: S2, cmp: F) -> stream::merge_sorted_by::MergeSortedBy<Self, S2, F>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        S2: futures::Stream<Item = <Self as futures::Stream>::Item>,
+        F: FnMut(&<Self as futures::Stream>::Item, &<Self as futures::Stream>::Item) -> std::cmp::Ordering;
+
+    /// Merges two sorted streams of `Result<T, E>` into one sorted stream, comparing the `Ok`
+    /// values with `cmp`.
+    ///
+    /// An `Err` value is emitted in position, right after the item that preceded it in its own
+    /// stream, and the merge carries on with the remaining items of both streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let left = futures::stream::iter(vec![Ok(1), Err("bad page"), Ok(4)]);
+    /// let right = futures::stream::iter(vec![Ok(2), Ok(3)]);
+    /// let stream = left.merge_sorted_res_by(right, |a, b| a.cmp(b));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(1), Err("bad page"), Ok(2), Ok(3), Ok(4)]);
+    /// ```
+    fn merge_sorted_res_by<S2, F, T, E>(self, other: S2, cmp: F) -> stream::merge_sorted_by::MergeSortedBy<Self, S2, merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        S2: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering;
+
+    /// Merges this stream and any number of `others`, all sorted, into one sorted stream,
+    /// comparing items with `cmp`.
+    ///
+    /// The next item of every stream is kept in a binary heap, and an item is only emitted once
+    /// the next item of every stream is known, or the stream has ended. The merge is stable: for
+    /// equal items, the items of `self` are emitted first, followed by the items of `others` in
+    /// order.
+    ///
+    /// All streams have the same type and must be [`Unpin`]. Streams of different types can be
+    /// merged after [`boxed`](futures::StreamExt::boxed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let mut shards = vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]].into_iter().map(futures::stream::iter);
+    /// let first = shards.next().unwrap();
+    /// let stream = first.kmerge_by(shards, |a, b| a.cmp(b));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    fn kmerge_by<I, F>(self, others: I, cmp: F) -> stream::kmerge_by::KMergeBy<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream + Unpin,
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&<Self as futures::Stream>::Item, &<Self as futures::Stream>::Item) -> std::cmp::Ordering;
+
+    /// Merges this stream and any number of `others`, all sorted streams of `Result<T, E>`, into
+    /// one sorted stream, comparing the `Ok` values with `cmp`.
+    ///
+    /// An `Err` value is emitted in position, right after the item that preceded it in its own
+    /// stream, and the merge carries on with the remaining items of every stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// let first = futures::stream::iter(vec![Ok(1), Ok(4)]);
+    /// let others = [
+    ///     futures::stream::iter(vec![Ok(2), Err("corrupt entry"), Ok(5)]),
+    ///     futures::stream::iter(vec![Ok(3)]),
+    /// ];
+    /// let stream = first.kmerge_res_by(others, |a, b| a.cmp(b));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(1), Ok(2), Err("corrupt entry"), Ok(3), Ok(4), Ok(5)]);
+    /// ```
+    fn kmerge_res_by<I, F, T, E>(self, others: I, cmp: F) -> stream::kmerge_by::KMergeBy<Self, merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>> + Unpin,
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering;
+
+    /// Yields an [`Elapsed`](crate::timer::Elapsed) error whenever the next item of the stream
+    /// does not arrive within `duration`.
+    ///
+    /// Items are wrapped in `Ok`. The deadline restarts after every item and every error, and
+    /// the stream ends when the inner stream ends.
+    ///
+    /// The returned stream measures time with the [system clock](crate::timer::SystemClock). Call
+    /// `with_clock` on it to use another [`Clock`](crate::timer::Clock), such as a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use std::time::Duration;
+    ///
+    /// let stream = futures::stream::iter(vec![1, 2]).timeout_each(Duration::from_secs(1));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![Ok(1), Ok(2)]);
+    /// ```
+    #[cfg(feature = "timer")]
+    fn timeout_each(self, duration: std::time::Duration) -> stream::timeout_each::TimeoutEach<Self, timer::SystemClock>
+    where
+        Self: Sized;
+
+    /// Ends the stream when the next item does not arrive within `duration`.
+    ///
+    /// The deadline restarts after every item. Whether the stream was ended by the timeout can
+    /// be checked afterwards with the `timed_out` method of the returned stream.
+    ///
+    /// The returned stream measures time with the [system clock](crate::timer::SystemClock). Call
+    /// `with_clock` on it to use another [`Clock`](crate::timer::Clock), such as a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use std::time::Duration;
+    ///
+    /// let stream = futures::StreamExt::chain(futures::stream::iter(vec![1, 2]), futures::stream::pending());
+    /// let mut stream = stream.idle_timeout(Duration::from_millis(10));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(&mut stream).collect();
+    /// assert_eq!(collected, vec![1, 2]);
+    /// assert!(stream.timed_out());
+    /// ```
+    #[cfg(feature = "timer")]
+    fn idle_timeout(self, duration: std::time::Duration) -> stream::idle_timeout::IdleTimeout<Self, timer::SystemClock>
+    where
+        Self: Sized;
+
+    /// Buffers the items of the stream into chunks of at most `max_len` items.
+    ///
+    /// A chunk is emitted when it is full, or when `max_wait` has passed since its first item
+    /// was buffered, whichever comes first. The remaining items are emitted as a final chunk
+    /// when the stream ends.
+    ///
+    /// The returned stream measures time with the [system clock](crate::timer::SystemClock). Call
+    /// `with_clock` on it to use another [`Clock`](crate::timer::Clock), such as a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use std::time::Duration;
+    ///
+    /// let stream = futures::stream::iter(1..=5).chunks_timeout(2, Duration::from_secs(1));
+    ///
+    /// let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    /// assert_eq!(collected, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    /// ```
+    #[cfg(feature = "timer")]
+    fn chunks_timeout(self, max_len: usize, max_wait: std::time::Duration) -> stream::chunks_timeout::ChunksTimeout<Self, timer::SystemClock>
+    where
+        Self: Sized,
+        Self: futures::Stream;
+
+    /// Emits the latest item of the stream once no new item has arrived for `duration`.
+    ///
+    /// Items that are superseded within `duration` are dropped. When the stream ends, the latest
+    /// item is emitted right away.
+    ///
+    /// The returned stream measures time with the [system clock](crate::timer::SystemClock). Call
+    /// `with_clock` on it to use another [`Clock`](crate::timer::Clock), such as a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use futures::FutureExt as _;
+    /// use futures::StreamExt as _;
+    /// use std::time::Duration;
+    /// use type_toppings::timer::MockClock;
+    ///
+    /// let clock = MockClock::new();
+    /// let (tx, rx) = futures::channel::mpsc::unbounded();
+    /// let mut stream = rx.debounce(Duration::from_millis(100)).with_clock(clock.clone());
+    ///
+    /// tx.unbounded_send("a").unwrap();
+    /// tx.unbounded_send("ab").unwrap();
+    /// assert_eq!(stream.next().now_or_never(), None);
+    ///
+    /// clock.advance(Duration::from_millis(100));
+    /// assert_eq!(stream.next().now_or_never(), Some(Some("ab")));
+    /// ```
+    #[cfg(feature = "timer")]
+    fn debounce(self, duration: std::time::Duration) -> stream::debounce::Debounce<Self, timer::SystemClock>
+    where
+        Self: Sized,
+        Self: futures::Stream;
+
+    /// Emits at most one item of the stream per `interval`.
+    ///
+    /// By default, the first item of an interval is emitted right away (the leading edge), and
+    /// the latest item received during the interval is emitted when it ends (the trailing edge),
+    /// starting a new interval. Other items are dropped. Either edge can be disabled with the
+    /// `leading` and `trailing` methods of the returned stream. If both are disabled, no items
+    /// are emitted.
+    ///
+    /// The returned stream measures time with the [system clock](crate::timer::SystemClock). Call
+    /// `with_clock` on it to use another [`Clock`](crate::timer::Clock), such as a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::StreamExt as _;
+    /// use futures::FutureExt as _;
+    /// use futures::StreamExt as _;
+    /// use std::time::Duration;
+    /// use type_toppings::timer::MockClock;
+    ///
+    /// let clock = MockClock::new();
+    /// let (tx, rx) = futures::channel::mpsc::unbounded();
+    /// let mut stream = rx.throttle(Duration::from_millis(100)).with_clock(clock.clone());
+    ///
+    /// tx.unbounded_send(1).unwrap();
+    /// tx.unbounded_send(2).unwrap();
+    /// tx.unbounded_send(3).unwrap();
+    /// assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+    /// assert_eq!(stream.next().now_or_never(), None);
+    ///
+    /// clock.advance(Duration::from_millis(100));
+    /// assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+    /// ```
+    #[cfg(feature = "timer")]
+    fn throttle(self, interval: std::time::Duration) -> stream::throttle::Throttle<Self, timer::SystemClock>
+    where
+        Self: Sized,
+        Self: futures::Stream;
+}
+
+/// [`std::iter::Iterator`] extensions.
+#[cfg(feature = "iterator")]
+pub trait IteratorExt {
+    /// Transforms the items in the iterator using the `Into` trait to convert
+    /// from `T` to `U`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt;
+    /// let data: Vec<_> = vec![1_u8, 3_u8]
+    ///     .into_iter()
+    ///     .map_into::<i32>()
+    ///     .collect();
+    /// assert_eq!(data, vec![1_i32, 3_i32]);
+    /// ```
+    fn map_into<U>(self) -> iterator::map_into::MapInto<Self, U>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Into<U>;
+
+    /// Transforms the items in the iterator using the `TryInto` trait to convert
+    /// from `T` to `Result<U, T::Error>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt;
+    /// let data: Vec<_> = vec![1_i32, -1_i32]
+    ///     .into_iter()
+    ///     .try_map_into::<u8>()
+    ///     .collect();
+    /// assert_eq!(data[0], Ok(1_u8));
+    /// assert!(data[1].is_err());
+    /// ```
+    fn try_map_into<U>(self) -> iterator::try_map_into::TryMapInto<Self, U>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: TryInto<U>;
+
+    /// Transforms the `Some` values in iterators of `Option<T>` using the given function `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt;
+    /// let data: Vec<_> = vec![Some(1), None, Some(3)].into_iter().map_opt(|x| x * 2).collect();
+    /// assert_eq!(data, vec![Some(2), None, Some(6)]);
+    /// ```
+    fn map_opt<T, U, F>(self, f: F) -> iterator::map_opt::MapOpt<Self, F>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Option<T>>,
+        F: FnMut(T) -> U;
+
+    /// Transforms the `Ok` values in iterators of `Result<T, E>` using the given function `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt;
+    /// let data = [Ok(1), Err("some error"), Ok(3)]
+    ///     .into_iter()
+    ///     .map_res(|x| x * 2)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(data, vec![Ok(2), Err("some error"), Ok(6)]);
+    /// ```
+    fn map_res<F, T, U, E>(self, f: F) -> iterator::map_res::MapRes<Self, F>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        F: FnMut(T) -> U;
+
+    /// Transforms the `Err` values in iterators of `Result<T, E>` using the given function `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt;
+    /// let data = [Ok(1), Err("unexpected thing happened"), Ok(3)]
+    ///     .into_iter()
+    ///     .map_res_err(|err| format!("Oh no: {err}"))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(data, vec![Ok(1), Err("Oh no: unexpected thing happened".to_string()), Ok(3)]);
+    /// ```
+    fn map_res_err<F, T, U, E>(self, f: F) -> iterator::map_res_err::MapResErr<Self, F>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        F: FnMut(E) -> U;
+
+    /// Transforms the `Ok` values in iterators of `Result<T, E>` using the `TryInto` trait to
+    /// convert from `T` to `U`.
+    ///
+    /// Conversion errors are converted into `E` using the `From` trait, like the `?` operator does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Missing,
+    ///     OutOfRange,
+    /// }
+    ///
+    /// impl From<std::num::TryFromIntError> for Error {
+    ///     fn from(_: std::num::TryFromIntError) -> Self {
+    ///         Error::OutOfRange
+    ///     }
+    /// }
+    ///
+    /// let data = [Ok(1_i32), Err(Error::Missing), Ok(-1_i32)]
+    ///     .into_iter()
+    ///     .map_res_try_into::<u8, _, _>()
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(data, vec![Ok(1_u8), Err(Error::Missing), Err(Error::OutOfRange)]);
+    /// ```
+    fn map_res_try_into<U, T, E>(self) -> iterator::map_res_try_into::MapResTryInto<Self, U>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        T: TryInto<U>,
+        E: From<T::Error>;
+
+    /// Converts each element of the iterator to a string and joins them into a single string, separated by the specified separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let numbers = vec![1, 2, 3];
+    /// let sequence = numbers.into_iter().join_as_strings(", ");
+    /// assert_eq!(sequence, "1, 2, 3");
+    ///
+    /// let words = vec!["hello", "world"];
+    /// let sentence = words.into_iter().join_as_strings(" - ");
+    /// assert_eq!(sentence, "hello - world");
+    /// ```
+    fn join_as_strings(self, separator: &str) -> String
+    where
+        Self: Iterator,
+        <Self as Iterator>::Item: ToString;
+
+    /// Lazily formats an iterator of key/value pairs, separating each key from its value with
+    /// `kv_sep` and the pairs from each other with `pair_sep`.
+    ///
+    /// Nothing is formatted until the returned value is displayed, and the pairs are written
+    /// straight to the formatter without allocating intermediate strings. Formatting options, such
+    /// as the precision, apply to every key and value.
+    ///
+    /// The value can be displayed any number of times, e.g. by several logging layers, as each
+    /// time iterates over a clone of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// use std::collections::BTreeMap;
+    ///
+    /// let headers = BTreeMap::from([("method", "GET"), ("path", "/")]);
+    /// let line = format!("request {{{}}}", headers.iter().join_kv(", ", "="));
+    /// assert_eq!(line, "request {method=GET, path=/}");
+    /// ```
+    fn join_kv<'a, K, V>(self, pair_sep: &'a str, kv_sep: &'a str) -> iterator::join_kv::JoinKv<'a, Self>
+    where
+        Self: Sized,
+        Self: Iterator<Item = (K, V)> + Clone,
+        K: std::fmt::Display,
+        V: std::fmt::Display;
+
+    /// Lazily formats the items of the iterator with the closure `f`, separated by `sep`.
+    ///
+    /// The closure receives each item together with the formatter to write it to. Nothing is
+    /// formatted until the returned value is displayed, and the items are written straight to the
+    /// formatter without allocating intermediate strings, so calls can be nested to render nested
+    /// structures.
+    ///
+    /// The value can be displayed any number of times, e.g. by several logging layers, as each
+    /// time iterates over a clone of the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let points = [(1, 2), (3, 4)];
+    /// let line = points.iter().format_with(" -> ", |(x, y), f| write!(f, "({x}, {y})"));
+    /// assert_eq!(line.to_string(), "(1, 2) -> (3, 4)");
+    /// ```
+    fn format_with<F>(self, sep: &str, f: F) -> iterator::format_with::FormatWith<'_, Self, F>
+    where
+        Self: Sized,
+        Self: Iterator + Clone,
+        F: Fn(<Self as Iterator>::Item, &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+
+    /// Collects the `Ok` values of an iterator of `Result<T, E>` into a collection, accumulating
+    /// every error rather than stopping at the first one.
+    ///
+    /// Unlike `collect::<Result<C, E>>()`, the whole iterator is always consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let all_ok: Result<Vec<_>, _> = [Ok(1), Ok(2)].into_iter().collect_all::<_, _, &str>();
+    /// assert_eq!(all_ok, Ok(vec![1, 2]));
+    ///
+    /// let some_err: Result<Vec<i32>, _> = [Ok(1), Err("a"), Ok(3), Err("b")].into_iter().collect_all();
+    /// assert_eq!(some_err, Err(vec!["a", "b"]));
+    /// ```
+    fn collect_all<C, T, E>(self) -> Result<C, Vec<E>>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>;
+
+    /// Like [`IteratorExt::collect_all`], but wraps the accumulated errors in a
+    /// [Report](crate::report::Report) of a [MultiError](crate::report::MultiError), which
+    /// includes the chain of source errors of every error when displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// use derive_more::{Display, Error};
+    ///
+    /// #[derive(Debug, Error, Display)]
+    /// #[display("Invalid entry {_0}")]
+    /// struct InvalidEntry(#[error(not(source))] u32);
+    ///
+    /// let entries: Vec<u32> = [Ok(1), Err(InvalidEntry(2)), Err(InvalidEntry(3))]
+    ///     .into_iter()
+    ///     .collect_all_report()
+    ///     .expect("Validation failed");
+    /// ```
+    /// The above panics with
+    /// ```text
+    /// Validation failed: 2 errors occurred:
+    ///    0: Invalid entry 2
+    ///    1: Invalid entry 3
+    /// ```
+    fn collect_all_report<C, T, E>(self) -> Result<C, crate::report::Report<crate::report::MultiError<E>>>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+        E: std::error::Error;
+
+    /// Partitions an iterator of `Result<T, E>` into the `Ok` values and the `Err` values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let (oks, errs) = [Ok(1), Err("a"), Ok(3)].into_iter().partition_results();
+    /// assert_eq!(oks, vec![1, 3]);
+    /// assert_eq!(errs, vec!["a"]);
+    /// ```
+    fn partition_results<T, E>(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>;
+
+    /// Processes the `Ok` values of an iterator of `Result<T, E>` with the closure `f`, as a
+    /// plain iterator of `T`.
+    ///
+    /// Iteration stops at the first `Err`, which is then returned instead of the output of `f`.
+    /// This allows using regular iterator methods on fallible iterators without collecting first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let sum = [Ok(1), Ok(2), Ok(3)].into_iter().try_process(|iter| iter.sum::<i32>());
+    /// assert_eq!(sum, Ok::<_, &str>(6));
+    ///
+    /// let max = [Ok(1), Err("oops"), Ok(3)].into_iter().try_process(|iter| iter.max());
+    /// assert_eq!(max, Err("oops"));
+    /// ```
+    fn try_process<T, E, F, R>(self, f: F) -> Result<R, E>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        F: FnOnce(iterator::try_process::ProcessResults<'_, Self, E>) -> R;
+
+    /// Returns the only item of the iterator, or an error if the iterator is empty or contains
+    /// more than one item.
+    ///
+    /// At most two items are consumed. On error, the returned [`ExactlyOneError`] is an iterator
+    /// over the consumed items followed by the remaining items, so that no data is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// assert_eq!(std::iter::once(1).exactly_one().unwrap(), 1);
+    ///
+    /// let err = vec![1, 2, 3].into_iter().exactly_one().unwrap_err();
+    /// assert_eq!(err.to_string(), "expected exactly one item, but the iterator contained more than one");
+    /// assert_eq!(err.collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    fn exactly_one(self) -> Result<<Self as Iterator>::Item, ExactlyOneError<Self>>
+    where
+        Self: Sized,
+        Self: Iterator;
+
+    /// Returns the only item of the iterator if there is one, `None` if the iterator is empty,
+    /// or an error if the iterator contains more than one item.
+    ///
+    /// At most two items are consumed. On error, the returned [`ExactlyOneError`] is an iterator
+    /// over the consumed items followed by the remaining items, so that no data is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// assert_eq!(std::iter::empty::<i32>().at_most_one().unwrap(), None);
+    /// assert_eq!(std::iter::once(1).at_most_one().unwrap(), Some(1));
+    /// assert!(vec![1, 2].into_iter().at_most_one().is_err());
+    /// ```
+    fn at_most_one(self) -> Result<Option<<Self as Iterator>::Item>, ExactlyOneError<Self>>
+    where
+        Self: Sized,
+        Self: Iterator;
+
+    /// Merges two sorted iterators into one sorted iterator, comparing items with `cmp`.
+    ///
+    /// The merge is stable: for equal items, the items of `self` are yielded before the items
+    /// of `other`. If either iterator is not sorted, the output is not sorted either.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let merged: Vec<_> = vec![1, 4, 5].into_iter().merge_sorted_by(vec![2, 3, 6], |a, b| a.cmp(b)).collect();
+    /// assert_eq!(merged, vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn merge_sorted_by<J, F>(self, other: J, cmp: F) -> iterator::merge_sorted_by::MergeSortedBy<Self, J::IntoIter, F>
+    where
+        Self: Sized,
+        Self: Iterator,
+        J: IntoIterator<Item = <Self as Iterator>::Item>,
+        F: FnMut(&<Self as Iterator>::Item, &<Self as Iterator>::Item) -> std::cmp::Ordering;
+
+    /// Merges two sorted iterators of `Result<T, E>` into one sorted iterator, comparing the
+    /// `Ok` values with `cmp`.
+    ///
+    /// An `Err` value is yielded in position, right after the item that preceded it in its own
+    /// iterator, and the merge carries on with the remaining items of both iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let merged: Vec<_> = vec![Ok(1), Err("bad page"), Ok(4)]
+    ///     .into_iter()
+    ///     .merge_sorted_res_by(vec![Ok(2), Ok(3)], |a, b| a.cmp(b))
+    ///     .collect();
+    /// assert_eq!(merged, vec![Ok(1), Err("bad page"), Ok(2), Ok(3), Ok(4)]);
+    /// ```
+    fn merge_sorted_res_by<J, F, T, E>(self, other: J, cmp: F) -> iterator::merge_sorted_by::MergeSortedBy<Self, J::IntoIter, merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: Iterator<Item = Result<T, E>>,
+        J: IntoIterator<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering;
+
+    /// Merges any number of sorted iterators, yielded by this iterator, into one sorted
+    /// iterator, comparing items with `cmp`.
+    ///
+    /// The next item of every iterator is kept in a binary heap. The merge is stable: for equal
+    /// items, the items of earlier iterators are yielded first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let shards = vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]];
+    /// let merged: Vec<_> = shards.into_iter().kmerge_by(|a, b| a.cmp(b)).collect();
+    /// assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    fn kmerge_by<F>(self, cmp: F) -> iterator::kmerge_by::KMergeBy<<<Self as Iterator>::Item as IntoIterator>::IntoIter, F>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: IntoIterator,
+        F: FnMut(&<<Self as Iterator>::Item as IntoIterator>::Item, &<<Self as Iterator>::Item as IntoIterator>::Item) -> std::cmp::Ordering;
+
+    /// Merges any number of sorted iterators of `Result<T, E>`, yielded by this iterator, into
+    /// one sorted iterator, comparing the `Ok` values with `cmp`.
+    ///
+    /// An `Err` value is yielded in position, right after the item that preceded it in its own
+    /// iterator, and the merge carries on with the remaining items of every iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let shards = vec![vec![Ok(1), Ok(4)], vec![Ok(2), Err("corrupt entry"), Ok(5)], vec![Ok(3)]];
+    /// let merged: Vec<_> = shards.into_iter().kmerge_res_by(|a, b| a.cmp(b)).collect();
+    /// assert_eq!(merged, vec![Ok(1), Ok(2), Err("corrupt entry"), Ok(3), Ok(4), Ok(5)]);
+    /// ```
+    fn kmerge_res_by<F, T, E>(
+        self,
+        cmp: F,
+    ) -> iterator::kmerge_by::KMergeBy<<<Self as Iterator>::Item as IntoIterator>::IntoIter, merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: IntoIterator<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering;
+
+    /// Yields overlapping windows of `N` consecutive items as arrays, like [`slice::windows`]
+    /// but for iterators.
+    ///
+    /// The last `N` items are kept in a ring buffer, and every window is a clone of them. If
+    /// the iterator has fewer than `N` items, no windows are yielded.
+    ///
+    /// Using a window size of zero fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let samples = [1.0, 2.0, 6.0, 3.0];
+    /// let moving_average: Vec<f64> = samples.into_iter().windows_array::<2>().map(|[a, b]| (a + b) / 2.0).collect();
+    /// assert_eq!(moving_average, vec![1.5, 4.0, 4.5]);
+    /// ```
+    fn windows_array<const N: usize>(self) -> iterator::windows_array::WindowsArray<Self, N>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Clone;
+
+    /// Yields non-overlapping chunks of `N` consecutive items as arrays, like
+    /// [`slice::chunks_exact`] but for iterators.
+    ///
+    /// If the number of items is not a multiple of `N`, the items left over at the end are not
+    /// yielded, but can be retrieved with the `remainder` method of the returned iterator once it
+    /// is exhausted.
+    ///
+    /// Using a chunk size of zero fails to compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let mut frames = (1..=7).chunks_array::<3>();
+    /// assert_eq!(frames.next(), Some([1, 2, 3]));
+    /// assert_eq!(frames.next(), Some([4, 5, 6]));
+    /// assert_eq!(frames.next(), None);
+    /// assert_eq!(frames.remainder(), &[7]);
+    /// ```
+    fn chunks_array<const N: usize>(self) -> iterator::chunks_array::ChunksArray<Self, N>
+    where
+        Self: Sized,
+        Self: Iterator;
+
+    /// Groups runs of consecutive items with equal keys, yielding each key together with a
+    /// `Vec` of the items of its run.
+    ///
+    /// Items with equal keys that are not consecutive end up in separate runs. See
+    /// [`IteratorExt::lazy_chunk_by_key`] for a variant that does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let log = ["INFO a", "INFO b", "WARN c", "INFO d"];
+    /// let runs: Vec<_> = log.into_iter().chunk_by_key(|line| &line[..4]).collect();
+    /// assert_eq!(runs, vec![("INFO", vec!["INFO a", "INFO b"]), ("WARN", vec!["WARN c"]), ("INFO", vec!["INFO d"])]);
+    /// ```
+    fn chunk_by_key<K, F>(self, f: F) -> iterator::chunk_by_key::ChunkByKey<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: PartialEq;
+
+    /// Groups runs of consecutive items with equal keys, like [`IteratorExt::chunk_by_key`],
+    /// but without allocating.
+    ///
+    /// Call `next_chunk` on the returned value to get the next run, which is an iterator over
+    /// the items of the run that pulls them from this iterator on demand. Items of a run that are
+    /// not consumed are skipped when the next run is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let mut runs = [1, 1, 2, 3, 3, 3].into_iter().lazy_chunk_by_key(|x| *x);
+    ///
+    /// let mut summary = Vec::new();
+    /// while let Some(run) = runs.next_chunk() {
+    ///     let key = *run.key();
+    ///     summary.push((key, run.count()));
+    /// }
+    /// assert_eq!(summary, vec![(1, 2), (2, 1), (3, 3)]);
+    /// ```
+    fn lazy_chunk_by_key<K, F>(self, f: F) -> iterator::chunk_by_key::LazyChunkByKey<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: PartialEq;
+
+    /// Yields only the first item of each run of consecutive items with equal keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let readings = [(0, "ok"), (1, "ok"), (2, "fail"), (3, "ok")];
+    /// let changes: Vec<_> = readings.into_iter().dedup_by_key(|(_, status)| *status).collect();
+    /// assert_eq!(changes, vec![(0, "ok"), (2, "fail"), (3, "ok")]);
+    /// ```
+    fn dedup_by_key<K, F>(self, f: F) -> iterator::dedup_by_key::DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: PartialEq;
+
+    /// Yields each run of consecutive equal items once, together with the length of the run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let runs: Vec<_> = "aaabcc".chars().dedup_with_count().collect();
+    /// assert_eq!(runs, vec![(3, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    fn dedup_with_count(self) -> iterator::dedup_with_count::DedupWithCount<Self>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialEq;
+
+    /// Iterates two iterators simultaneously until both are exhausted, unlike [`Iterator::zip`]
+    /// which stops at the shorter one.
+    ///
+    /// Each item is an [`EitherOrBoth`], holding the items of both iterators, or of only one of
+    /// them once the other is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// use type_toppings::EitherOrBoth;
+    ///
+    /// let before = ["a", "b", "c"];
+    /// let after = ["a", "x"];
+    /// let diff: Vec<_> = before
+    ///     .into_iter()
+    ///     .zip_longest(after)
+    ///     .filter(|pair| pair.both().is_none_or(|(old, new)| old != new))
+    ///     .collect();
+    /// assert_eq!(diff, vec![EitherOrBoth::Both("b", "x"), EitherOrBoth::Left("c")]);
+    /// ```
+    fn zip_longest<J>(self, other: J) -> iterator::zip_longest::ZipLongest<Self, J::IntoIter>
+    where
+        Self: Sized,
+        Self: Iterator,
+        J: IntoIterator;
+
+    /// Iterates two iterators simultaneously, panicking if they turn out to have different
+    /// lengths.
+    ///
+    /// The closure `f` is only evaluated if the lengths differ.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one iterator is exhausted before the other, with a panic message provided by the
+    /// closure `f`, followed by which iterator is longer.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let names = ["a", "b", "c"];
+    /// let values = [1, 2];
+    /// for (name, value) in names.into_iter().zip_eq(values, || "every column needs a value") {
+    ///     println!("{name}: {value}");
+    /// }
+    /// ```
+    fn zip_eq<J, M, F>(self, other: J, f: F) -> iterator::zip_eq::ZipEq<Self, J::IntoIter, F>
+    where
+        Self: Sized,
+        Self: Iterator,
+        J: IntoIterator,
+        F: FnOnce() -> M,
+        M: AsRef<str>;
+
+    /// Counts the occurrences of each distinct item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let counts = "hello".chars().counts();
+    /// assert_eq!(counts[&'l'], 2);
+    /// assert_eq!(counts[&'o'], 1);
+    /// assert_eq!(counts.len(), 4);
+    /// ```
+    fn counts(self) -> std::collections::HashMap<<Self as Iterator>::Item, usize>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Eq + std::hash::Hash;
+
+    /// Counts the items by the key that the closure `f` derives from each item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let words = ["apple", "avocado", "banana", "cherry", "blueberry"];
+    /// let counts = words.into_iter().counts_by(|word| word.chars().next());
+    /// assert_eq!(counts[&Some('a')], 2);
+    /// assert_eq!(counts[&Some('b')], 2);
+    /// assert_eq!(counts[&Some('c')], 1);
+    /// ```
+    fn counts_by<K, F>(self, f: F) -> std::collections::HashMap<K, usize>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(<Self as Iterator>::Item) -> K,
+        K: Eq + std::hash::Hash;
+
+    /// Finds both the minimum and the maximum of the iterator in a single pass.
+    ///
+    /// Like [`Iterator::min`] and [`Iterator::max`], the first of several equally minimum items
+    /// and the last of several equally maximum items are returned. Items that are not comparable
+    /// to the current minimum and maximum are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// use type_toppings::MinMax;
+    ///
+    /// assert_eq!([3, 1, 4, 1, 5].into_iter().min_max(), MinMax::Pair(1, 5));
+    /// assert_eq!([3].into_iter().min_max(), MinMax::One(3));
+    /// assert_eq!(std::iter::empty::<i32>().min_max(), MinMax::Empty);
+    /// ```
+    fn min_max(self) -> MinMax<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialOrd;
+
+    /// Computes summary statistics of numeric items in a single pass: the count, sum, mean,
+    /// variance, minimum and maximum.
+    ///
+    /// The statistics are computed as `f64`, and the items can be of any primitive numeric type
+    /// (see [`ToF64`]). Integers that are too large to be represented exactly are rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let stats = [2, 4, 4, 4, 5, 5, 7, 9].into_iter().stats();
+    /// assert_eq!(stats.count(), 8);
+    /// assert_eq!(stats.mean(), Some(5.0));
+    /// assert_eq!(stats.variance(), Some(4.0));
+    /// assert_eq!(stats.min(), Some(2.0));
+    /// assert_eq!(stats.max(), Some(9.0));
+    /// ```
+    fn stats(self) -> Stats
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: ToF64;
+
+    /// Collects the items of the iterator and returns an iterator over them in sorted order.
+    ///
+    /// The sort is stable, so equal items keep their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let sorted: Vec<_> = [3, 1, 2].into_iter().sorted().map(|n| n * 10).collect();
+    /// assert_eq!(sorted, vec![10, 20, 30]);
+    /// ```
+    fn sorted(self) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Ord;
+
+    /// Collects the items of the iterator and returns an iterator over them, sorted by the key
+    /// that the closure `f` derives from each item.
+    ///
+    /// The sort is stable, so items with equal keys keep their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let words = ["pear", "fig", "plum", "kiwi"];
+    /// let sorted: Vec<_> = words.into_iter().sorted_by_key(|word| word.len()).collect();
+    /// assert_eq!(sorted, vec!["fig", "pear", "plum", "kiwi"]);
+    /// ```
+    fn sorted_by_key<K, F>(self, f: F) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: Ord;
+
+    /// Collects the items of the iterator and returns an iterator over them, sorted with the
+    /// comparison function `cmp`.
+    ///
+    /// The sort is unstable, so equal items may be reordered, but it does not allocate beyond
+    /// collecting the items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let sorted: Vec<_> = [3, 1, 2].into_iter().sorted_unstable_by(|a, b| b.cmp(a)).collect();
+    /// assert_eq!(sorted, vec![3, 2, 1]);
+    /// ```
+    fn sorted_unstable_by<F>(self, cmp: F) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item, &<Self as Iterator>::Item) -> std::cmp::Ordering;
+
+    /// Yields only the first occurrence of each distinct item, lazily.
+    ///
+    /// Every distinct item is cloned into a `HashSet` to recognize later occurrences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let unique: Vec<_> = [3, 1, 3, 2, 1].into_iter().unique().collect();
+    /// assert_eq!(unique, vec![3, 1, 2]);
+    /// ```
+    fn unique(self) -> iterator::unique::Unique<Self>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Eq + std::hash::Hash + Clone;
+
+    /// Yields only the first item for each distinct key that the closure `f` derives from the
+    /// items, lazily.
+    ///
+    /// The keys are kept in a `HashSet` to recognize later items with the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let users = [("alice", 1), ("bob", 2), ("alice", 3)];
+    /// let first_visits: Vec<_> = users.into_iter().unique_by(|(name, _)| *name).collect();
+    /// assert_eq!(first_visits, vec![("alice", 1), ("bob", 2)]);
+    /// ```
+    fn unique_by<K, F>(self, f: F) -> iterator::unique::UniqueBy<Self, F, K>
+    where
+        Self: Sized,
+        Self: Iterator,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+        K: Eq + std::hash::Hash;
+
+    /// Returns an iterator over the `k` smallest items, in ascending order.
+    ///
+    /// The whole iterator is consumed, but at most `k` items are kept at a time, in a bounded
+    /// heap. If the iterator has fewer than `k` items, all of them are returned. If `k` is zero,
+    /// nothing is returned and no items are pulled from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let smallest: Vec<_> = [5, 1, 4, 3, 9, 2].into_iter().k_smallest(3).collect();
+    /// assert_eq!(smallest, vec![1, 2, 3]);
+    /// ```
+    fn k_smallest(self, k: usize) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Ord;
+
+    /// Returns an iterator over the `k` largest items, in descending order.
+    ///
+    /// The whole iterator is consumed, but at most `k` items are kept at a time, in a bounded
+    /// heap. If the iterator has fewer than `k` items, all of them are returned. If `k` is zero,
+    /// nothing is returned and no items are pulled from the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use type_toppings::IteratorExt as _;
+    /// let largest: Vec<_> = [5, 1, 4, 3, 9, 2].into_iter().k_largest(3).collect();
+    /// assert_eq!(largest, vec![9, 5, 4]);
+    /// ```
+    fn k_largest(self, k: usize) -> std::vec::IntoIter<<Self as Iterator>::Item>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: Ord;
+
+    /// Passes the items of the iterator through unchanged, asserting that they are sorted in
+    /// non-decreasing order.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics when an item is smaller than the item before it, or is not comparable to it, with a
+    /// panic message provided by the closure `f`, followed by the index and the offending item.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![1, 3, 2]
+    ///     .into_iter()
+    ///     .assert_sorted(|item| format!("item {item} out of order"))
+    ///     .collect();
+    /// ```
+    fn assert_sorted<M, F>(self, f: F) -> iterator::assert_sorted::AssertSorted<Self, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialOrd + Clone + std::fmt::Debug,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that the keys returned by
+    /// the closure `key` are unique.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key of an item has been seen before, with a panic message provided by the
+    /// closure `f`, followed by the index and the offending item.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![(1, "a"), (2, "b"), (1, "c")]
+    ///     .into_iter()
+    ///     .assert_unique_by_key(|(id, _)| *id, |(id, _)| format!("duplicate id {id}"))
+    ///     .collect();
+    /// ```
+    fn assert_unique_by_key<K, Key, M, F>(self, key: K, f: F) -> iterator::assert_unique_by_key::AssertUniqueByKey<Self, K, Key, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        K: FnMut(&<Self as Iterator>::Item) -> Key,
+        Key: std::hash::Hash + Eq,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that the iterator yields
+    /// exactly `len` items.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails. A missing item can only be
+    /// detected if the iterator is consumed until it is exhausted.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the iterator yields more or fewer than `len` items, with a panic message
+    /// provided by the closure `f`, followed by the index and the first surplus item, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![1, 2, 3]
+    ///     .into_iter()
+    ///     .assert_len(2, || "expected a pair")
+    ///     .collect();
+    /// ```
+    fn assert_len<M, F>(self, len: usize, f: F) -> iterator::assert_len::AssertLen<Self, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        F: FnOnce() -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that every item satisfies
+    /// the given `predicate`.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails.
+    ///
+    /// # Panics
+    ///
+    /// Panics when an item does not satisfy the predicate, with a panic message provided by the
+    /// closure `f`, followed by the index and the offending item.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![2, 3, 4]
+    ///     .into_iter()
+    ///     .assert_all(|x| x % 2 == 0, |x| format!("{x} is odd"))
+    ///     .collect();
+    /// ```
+    fn assert_all<P, M, F>(self, predicate: P, f: F) -> iterator::assert_all::AssertAll<Self, P, F, false>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        P: FnMut(&<Self as Iterator>::Item) -> bool,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that they are sorted in
+    /// non-decreasing order.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails.
+    ///
+    /// When debug assertions are disabled, no checks are performed and the items are passed
+    /// through as is. The returned adapter still wraps the iterator, at the small cost of
+    /// forwarding each item, and the bounds on the items still apply, so the same code compiles
+    /// with and without debug assertions.
+    ///
+    /// # Panics
+    ///
+    /// Panics when an item is smaller than the item before it, or is not comparable to it, with a
+    /// panic message provided by the closure `f`, followed by the index and the offending item.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![1, 3, 2]
+    ///     .into_iter()
+    ///     .debug_assert_sorted(|item| format!("item {item} out of order"))
+    ///     .collect();
+    /// ```
+    fn debug_assert_sorted<M, F>(self, f: F) -> iterator::assert_sorted::AssertSorted<Self, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: PartialOrd + Clone + std::fmt::Debug,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that the keys returned by
+    /// the closure `key` are unique.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails.
+    ///
+    /// When debug assertions are disabled, no checks are performed and the items are passed
+    /// through as is. The returned adapter still wraps the iterator, at the small cost of
+    /// forwarding each item, and the bounds on the items still apply, so the same code compiles
+    /// with and without debug assertions. No keys are computed or stored.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the key of an item has been seen before, with a panic message provided by the
+    /// closure `f`, followed by the index and the offending item.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![(1, "a"), (2, "b"), (1, "c")]
+    ///     .into_iter()
+    ///     .debug_assert_unique_by_key(|(id, _)| *id, |(id, _)| format!("duplicate id {id}"))
+    ///     .collect();
+    /// ```
+    fn debug_assert_unique_by_key<K, Key, M, F>(self, key: K, f: F) -> iterator::assert_unique_by_key::AssertUniqueByKey<Self, K, Key, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        K: FnMut(&<Self as Iterator>::Item) -> Key,
+        Key: std::hash::Hash + Eq,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that the iterator yields
+    /// exactly `len` items.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails. A missing item can only be
+    /// detected if the iterator is consumed until it is exhausted.
+    ///
+    /// When debug assertions are disabled, no checks are performed and the items are passed
+    /// through as is. The returned adapter still wraps the iterator, at the small cost of
+    /// forwarding each item, and the bounds on the items still apply, so the same code compiles
+    /// with and without debug assertions.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the iterator yields more or fewer than `len` items, with a panic message
+    /// provided by the closure `f`, followed by the index and the first surplus item, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![1, 2, 3]
+    ///     .into_iter()
+    ///     .debug_assert_len(2, || "expected a pair")
+    ///     .collect();
+    /// ```
+    fn debug_assert_len<M, F>(self, len: usize, f: F) -> iterator::assert_len::AssertLen<Self, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        F: FnOnce() -> M,
+        M: AsRef<str>;
+
+    /// Passes the items of the iterator through unchanged, asserting that every item satisfies
+    /// the given `predicate`.
+    ///
+    /// The closure `f` is only evaluated if the assertion fails.
+    ///
+    /// When debug assertions are disabled, no checks are performed and the items are passed
+    /// through as is. The returned adapter still wraps the iterator, at the small cost of
+    /// forwarding each item, and the bounds on the items still apply, so the same code compiles
+    /// with and without debug assertions.
+    ///
+    /// # Panics
+    ///
+    /// Panics when an item does not satisfy the predicate, with a panic message provided by the
+    /// closure `f`, followed by the index and the offending item.
+    ///
+    /// # Examples
+    ///
+    /// ```should_panic
+    /// # use type_toppings::IteratorExt as _;
+    /// let data: Vec<_> = vec![2, 3, 4]
+    ///     .into_iter()
+    ///     .debug_assert_all(|x| x % 2 == 0, |x| format!("{x} is odd"))
+    ///     .collect();
+    /// ```
+    fn debug_assert_all<P, M, F>(self, predicate: P, f: F) -> iterator::assert_all::AssertAll<Self, P, F, true>
+    where
+        Self: Sized,
+        Self: Iterator,
+        <Self as Iterator>::Item: std::fmt::Debug,
+        P: FnMut(&<Self as Iterator>::Item) -> bool,
+        F: FnOnce(&<Self as Iterator>::Item) -> M,
+        M: AsRef<str>;
 }
@@ -0,0 +1,136 @@
+//! Orderings shared by the sorted merge adapters of iterators and streams.
+
+use std::cmp::Ordering;
+
+/// An ordering used to merge sorted sources.
+///
+/// It is implemented for every `FnMut(&T, &T) -> Ordering`, and for [`ErrorsFirst`].
+pub trait MergeOrder<T> {
+    /// Compares two items.
+    fn compare(&mut self, a: &T, b: &T) -> Ordering;
+}
+
+impl<T, F> MergeOrder<T> for F
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    fn compare(&mut self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// An ordering of `Result<T, E>` items that compares the `Ok` values with a function, and
+/// orders `Err` values before everything else.
+///
+/// When merging, an `Err` value is therefore emitted as soon as it is next in its source,
+/// right after the item that preceded it in that source.
+#[derive(Debug)]
+pub struct ErrorsFirst<F> {
+    pub(crate) cmp: F,
+}
+
+impl<T, E, F> MergeOrder<Result<T, E>> for ErrorsFirst<F>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    fn compare(&mut self, a: &Result<T, E>, b: &Result<T, E>) -> Ordering {
+        match (a, b) {
+            (Ok(a), Ok(b)) => (self.cmp)(a, b),
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Err(_), Err(_)) => Ordering::Equal,
+        }
+    }
+}
+
+/// A binary heap of the next items of several sorted sources, ordered by a [`MergeOrder`] and
+/// then by source index, so that merges are stable.
+pub(crate) struct MergeHeap<T, S, F> {
+    entries: Vec<HeadTail<T, S>>,
+    cmp: F,
+}
+
+/// The next item of a source, together with the rest of the source.
+#[derive(Debug)]
+pub(crate) struct HeadTail<T, S> {
+    pub(crate) head: T,
+    pub(crate) tail: S,
+    pub(crate) source: usize,
+}
+
+impl<T, S, F> MergeHeap<T, S, F> {
+    pub(crate) fn entries(&self) -> &[HeadTail<T, S>] {
+        &self.entries
+    }
+}
+
+impl<T, S, F> MergeHeap<T, S, F>
+where
+    F: MergeOrder<T>,
+{
+    pub(crate) fn new(cmp: F) -> Self {
+        MergeHeap { entries: Vec::new(), cmp }
+    }
+
+    pub(crate) fn push(&mut self, entry: HeadTail<T, S>) {
+        self.entries.push(entry);
+        self.sift_up(self.entries.len() - 1);
+    }
+
+    /// Removes the first entry.
+    #[cfg(feature = "stream")]
+    pub(crate) fn pop(&mut self) -> Option<HeadTail<T, S>> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let entry = self.entries.swap_remove(0);
+        self.sift_down(0);
+        Some(entry)
+    }
+
+    /// Replaces the head of the first entry with `f(tail)`, or removes the entry if that
+    /// returns `None`, and returns the previous head.
+    #[cfg(feature = "iterator")]
+    pub(crate) fn pop_head(&mut self, f: impl FnOnce(&mut S) -> Option<T>) -> Option<T> {
+        let first = self.entries.first_mut()?;
+        let head = match f(&mut first.tail) {
+            Some(next) => std::mem::replace(&mut first.head, next),
+            None => self.entries.swap_remove(0).head,
+        };
+        self.sift_down(0);
+        Some(head)
+    }
+
+    /// Returns whether the entry at index `a` comes before the entry at index `b`.
+    fn less(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (&self.entries[a], &self.entries[b]);
+        self.cmp.compare(&a.head, &b.head).then(a.source.cmp(&b.source)) == Ordering::Less
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !self.less(index, parent) {
+                break;
+            }
+            self.entries.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let mut smallest = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < self.entries.len() && self.less(child, smallest) {
+                    smallest = child;
+                }
+            }
+            if smallest == index {
+                break;
+            }
+            self.entries.swap(index, smallest);
+            index = smallest;
+        }
+    }
+}
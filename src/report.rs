@@ -47,6 +47,97 @@ impl<E: Error> fmt::Debug for Report<E> {
     }
 }
 
+/// A collection of errors, for when several errors are accumulated rather than stopping at the
+/// first one.
+///
+/// When displayed, every error is listed together with its chain of source errors, in the same
+/// format as [Report].
+#[derive(Debug)]
+pub struct MultiError<E> {
+    pub(crate) errors: Vec<E>,
+}
+
+impl<E> MultiError<E> {
+    /// Returns the accumulated errors.
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+
+    /// Consumes the `MultiError`, returning the accumulated errors.
+    pub fn into_errors(self) -> Vec<E> {
+        self.errors
+    }
+}
+
+impl<E> From<Vec<E>> for MultiError<E> {
+    fn from(errors: Vec<E>) -> Self {
+        MultiError { errors }
+    }
+}
+
+impl<E: Error> fmt::Display for MultiError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.len() {
+            1 => write!(f, "1 error occurred:")?,
+            n => write!(f, "{n} errors occurred:")?,
+        }
+
+        for (ind, error) in self.errors.iter().enumerate() {
+            writeln!(f)?;
+            let mut indented = Indented { inner: f };
+            write!(indented, "{ind: >4}: {}", Report { error })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: Error> Error for MultiError<E> {}
+
+/// An error with a context message attached, describing what was being done when the error
+/// occurred.
+///
+/// When displayed, only the context message is shown. The original error is available as the
+/// source, so a [Report] shows both.
+#[derive(Debug)]
+pub struct ContextError<C, E> {
+    pub(crate) context: C,
+    pub(crate) error: E,
+}
+
+impl<C, E> ContextError<C, E> {
+    /// Returns the context message.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Returns the original error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Consumes the `ContextError`, returning the original error.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<C: fmt::Display, E> fmt::Display for ContextError<C, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.context.fmt(f)
+    }
+}
+
+impl<C, E> Error for ContextError<C, E>
+where
+    C: fmt::Display + fmt::Debug,
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
 #[derive(Clone, Debug)]
 struct Source<'a> {
     current: Option<&'a (dyn Error + 'static)>,
@@ -95,3 +186,39 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use derive_more::Display;
+    use derive_more::Error;
+
+    #[derive(Debug, Error, Display)]
+    #[display("Outer error {_0}")]
+    struct OuterError(#[error(not(source))] u32, #[error(source)] InnerError);
+
+    #[derive(Debug, Error, Display)]
+    #[display("Inner error")]
+    struct InnerError;
+
+    /// Tests the [MultiError] type.
+    mod multi_error {
+        use super::*;
+
+        #[test]
+        fn display_single() {
+            let errors = MultiError::from(vec![InnerError]);
+            assert_eq!(errors.to_string(), "1 error occurred:\n   0: Inner error");
+        }
+
+        #[test]
+        fn display_with_sources() {
+            let errors = MultiError::from(vec![OuterError(1, InnerError), OuterError(2, InnerError)]);
+            let report = Report::from(errors);
+            assert_eq!(
+                report.to_string(),
+                "2 errors occurred:\n   0: Outer error 1\n      \n      Caused by:\n            Inner error\n   1: Outer error 2\n      \n      Caused by:\n            Inner error"
+            );
+        }
+    }
+}
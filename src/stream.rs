@@ -1,3 +1,48 @@
+//! Stream constructors that complement [`StreamExt`](crate::StreamExt).
+//!
+//! With the `timer` feature, [`retrying`] creates a stream that recreates its source with
+//! exponential backoff whenever it ends or fails.
+
+#[cfg(feature = "timer")]
+pub(crate) mod chunks_timeout;
+pub(crate) mod collect_all;
+#[cfg(feature = "timer")]
+pub(crate) mod debounce;
+pub(crate) mod expect_with;
+pub(crate) mod fanout;
+#[cfg(feature = "timer")]
+pub(crate) mod idle_timeout;
+pub(crate) mod inspect_err;
+pub(crate) mod join_as_strings;
+pub(crate) mod kmerge_by;
+pub(crate) mod map_into;
+pub(crate) mod map_opt;
+pub(crate) mod map_res;
+pub(crate) mod map_res_concurrent;
+pub(crate) mod map_res_err;
+pub(crate) mod merge_sorted_by;
+pub(crate) mod partition_results;
+#[cfg(feature = "timer")]
+pub(crate) mod retrying;
+pub(crate) mod take_until_signal;
+#[cfg(feature = "timer")]
+pub(crate) mod throttle;
+#[cfg(feature = "timer")]
+pub(crate) mod timeout_each;
+
+#[cfg(feature = "timer")]
+pub use retrying::RetryAll;
+#[cfg(feature = "timer")]
+pub use retrying::RetryCause;
+#[cfg(feature = "timer")]
+pub use retrying::RetryEvent;
+#[cfg(feature = "timer")]
+pub use retrying::RetryPolicy;
+#[cfg(feature = "timer")]
+pub use retrying::Retrying;
+#[cfg(feature = "timer")]
+pub use retrying::retrying;
+
 impl<S> crate::StreamExt for S
 where
     S: futures::stream::Stream,
@@ -18,11 +63,364 @@ where
     {
         futures::StreamExt::chain(self, futures::stream::once(fut))
     }
+
+    fn chain_ready_if<T>(self, condition: bool, item: T) -> futures::stream::Chain<Self, futures::stream::Iter<std::option::IntoIter<T>>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>,
+    {
+        futures::StreamExt::chain(self, futures::stream::iter(condition.then_some(item)))
+    }
+
+    fn chain_ok<T, E>(self, item: T) -> futures::stream::Chain<Self, futures::stream::Once<std::future::Ready<Result<T, E>>>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+    {
+        self.chain_ready(Ok(item))
+    }
+
+    fn prepend_ready<T>(self, item: T) -> futures::stream::Chain<futures::stream::Once<std::future::Ready<T>>, Self>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>,
+    {
+        self.prepend_future(std::future::ready(item))
+    }
+
+    fn prepend_future<T, F>(self, fut: F) -> futures::stream::Chain<futures::stream::Once<F>, Self>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>,
+        F: core::future::Future<Output = T>,
+    {
+        futures::StreamExt::chain(futures::stream::once(fut), self)
+    }
+
+    fn prepend_ready_if<T>(self, condition: bool, item: T) -> futures::stream::Chain<futures::stream::Iter<std::option::IntoIter<T>>, Self>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = T>,
+    {
+        futures::StreamExt::chain(futures::stream::iter(condition.then_some(item)), self)
+    }
+
+    fn map_into<U>(self) -> map_into::MapInto<Self, U>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: Into<U>,
+    {
+        map_into::MapInto {
+            stream: self,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    fn map_opt<T, U, F>(self, f: F) -> map_opt::MapOpt<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Option<T>>,
+        F: FnMut(T) -> U,
+    {
+        map_opt::MapOpt { stream: self, f }
+    }
+
+    fn map_res<F, T, U, E>(self, f: F) -> map_res::MapRes<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(T) -> U,
+    {
+        map_res::MapRes { stream: self, f }
+    }
+
+    fn map_res_err<F, T, U, E>(self, f: F) -> map_res_err::MapResErr<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(E) -> U,
+    {
+        map_res_err::MapResErr { stream: self, f }
+    }
+
+    fn map_err_report<T, E>(self) -> map_res_err::MapResErr<Self, fn(E) -> crate::report::Report<E>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        E: std::error::Error,
+    {
+        self.map_res_err(Into::<crate::report::Report<_>>::into)
+    }
+
+    fn inspect_err<F, T, E>(self, f: F) -> inspect_err::InspectErr<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(&E),
+    {
+        inspect_err::InspectErr { stream: self, f }
+    }
+
+    fn expect_with<M, F, T, E>(self, f: F) -> expect_with::ExpectWith<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnOnce() -> M,
+        M: AsRef<str>,
+        E: std::error::Error,
+    {
+        expect_with::ExpectWith { stream: self, f: Some(f) }
+    }
+
+    fn map_res_concurrent<F, Fut, T, U, E>(self, limit: usize, f: F) -> map_res_concurrent::MapResConcurrent<Self, F, Fut, U, E>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(T) -> Fut,
+        Fut: core::future::Future<Output = U>,
+    {
+        assert!(limit > 0, "limit must be greater than zero");
+        map_res_concurrent::MapResConcurrent {
+            stream: futures::StreamExt::fuse(self),
+            f,
+            queue: futures::stream::FuturesOrdered::new(),
+            limit,
+        }
+    }
+
+    fn take_until_signal<Fut>(
+        self,
+        signal: Fut,
+    ) -> take_until_signal::TakeUntilSignal<Self, take_until_signal::DiscardOutput<Fut, <Self as futures::Stream>::Item>>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        Fut: core::future::Future,
+    {
+        let no_final_item: fn(Fut::Output) -> Option<<Self as futures::Stream>::Item> = |_| None;
+        self.take_until_signal_then(futures::FutureExt::map(signal, no_final_item))
+    }
+
+    fn take_until_signal_then<Fut>(self, signal: Fut) -> take_until_signal::TakeUntilSignal<Self, Fut>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        Fut: core::future::Future<Output = Option<<Self as futures::Stream>::Item>>,
+    {
+        take_until_signal::TakeUntilSignal::new(self, signal)
+    }
+
+    fn fanout(self, consumers: usize, capacity: usize, policy: fanout::FanoutPolicy) -> Vec<fanout::Fanout<Self>>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: Clone,
+    {
+        fanout::Fanout::new(self, consumers, capacity, policy)
+    }
+
+    fn broadcast(self, capacity: usize, policy: fanout::FanoutPolicy) -> fanout::Fanout<Self>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: Clone,
+    {
+        let mut consumers = fanout::Fanout::new(self, 1, capacity, policy);
+        consumers.remove(0)
+    }
+
+    fn join_as_strings(self, separator: &str) -> join_as_strings::JoinAsStrings<Self>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        <Self as futures::Stream>::Item: ToString,
+    {
+        join_as_strings::JoinAsStrings {
+            stream: self,
+            separator: separator.to_owned(),
+            output: None,
+        }
+    }
+
+    fn collect_all<C, T, E>(self) -> collect_all::CollectAll<Self, C, E>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        C: Default + Extend<T>,
+    {
+        collect_all::CollectAll {
+            stream: self,
+            collection: C::default(),
+            errors: Vec::new(),
+        }
+    }
+
+    fn partition_results<T, E>(self) -> partition_results::PartitionResults<Self, T, E>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+    {
+        partition_results::PartitionResults {
+            stream: self,
+            oks: Vec::new(),
+            errs: Vec::new(),
+        }
+    }
+
+    fn merge_sorted_by<S2, F>(self, other: S2, cmp: F) -> merge_sorted_by::MergeSortedBy<Self, S2, F>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+        S2: futures::Stream<Item = <Self as futures::Stream>::Item>,
+        F: FnMut(&<Self as futures::Stream>::Item, &<Self as futures::Stream>::Item) -> std::cmp::Ordering,
+    {
+        merge_sorted_by::MergeSortedBy {
+            left: self,
+            right: other,
+            left_head: None,
+            right_head: None,
+            left_done: false,
+            right_done: false,
+            cmp,
+        }
+    }
+
+    fn merge_sorted_res_by<S2, F, T, E>(self, other: S2, cmp: F) -> merge_sorted_by::MergeSortedBy<Self, S2, crate::merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>>,
+        S2: futures::Stream<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        merge_sorted_by::MergeSortedBy {
+            left: self,
+            right: other,
+            left_head: None,
+            right_head: None,
+            left_done: false,
+            right_done: false,
+            cmp: crate::merge::ErrorsFirst { cmp },
+        }
+    }
+
+    fn kmerge_by<I, F>(self, others: I, cmp: F) -> kmerge_by::KMergeBy<Self, F>
+    where
+        Self: Sized,
+        Self: futures::Stream + Unpin,
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&<Self as futures::Stream>::Item, &<Self as futures::Stream>::Item) -> std::cmp::Ordering,
+    {
+        kmerge_by::KMergeBy::new(std::iter::once(self).chain(others), cmp)
+    }
+
+    fn kmerge_res_by<I, F, T, E>(self, others: I, cmp: F) -> kmerge_by::KMergeBy<Self, crate::merge::ErrorsFirst<F>>
+    where
+        Self: Sized,
+        Self: futures::Stream<Item = Result<T, E>> + Unpin,
+        I: IntoIterator<Item = Self>,
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        kmerge_by::KMergeBy::new(std::iter::once(self).chain(others), crate::merge::ErrorsFirst { cmp })
+    }
+
+    #[cfg(feature = "timer")]
+    fn timeout_each(self, duration: std::time::Duration) -> timeout_each::TimeoutEach<Self, crate::timer::SystemClock>
+    where
+        Self: Sized,
+    {
+        timeout_each::TimeoutEach {
+            stream: self,
+            clock: crate::timer::SystemClock,
+            duration,
+            sleep: None,
+        }
+    }
+
+    #[cfg(feature = "timer")]
+    fn idle_timeout(self, duration: std::time::Duration) -> idle_timeout::IdleTimeout<Self, crate::timer::SystemClock>
+    where
+        Self: Sized,
+    {
+        idle_timeout::IdleTimeout {
+            stream: self,
+            clock: crate::timer::SystemClock,
+            duration,
+            sleep: None,
+            done: false,
+            timed_out: false,
+        }
+    }
+
+    #[cfg(feature = "timer")]
+    fn chunks_timeout(self, max_len: usize, max_wait: std::time::Duration) -> chunks_timeout::ChunksTimeout<Self, crate::timer::SystemClock>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+    {
+        assert!(max_len > 0, "max_len must be greater than zero");
+        chunks_timeout::ChunksTimeout {
+            stream: self,
+            clock: crate::timer::SystemClock,
+            // The buffer grows as needed, as `max_len` may be far larger than a typical chunk.
+            items: Vec::new(),
+            max_len,
+            max_wait,
+            sleep: None,
+            done: false,
+        }
+    }
+
+    #[cfg(feature = "timer")]
+    fn debounce(self, duration: std::time::Duration) -> debounce::Debounce<Self, crate::timer::SystemClock>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+    {
+        debounce::Debounce {
+            stream: self,
+            clock: crate::timer::SystemClock,
+            duration,
+            pending: None,
+            sleep: None,
+            stream_done: false,
+        }
+    }
+
+    #[cfg(feature = "timer")]
+    fn throttle(self, interval: std::time::Duration) -> throttle::Throttle<Self, crate::timer::SystemClock>
+    where
+        Self: Sized,
+        Self: futures::Stream,
+    {
+        throttle::Throttle {
+            stream: self,
+            clock: crate::timer::SystemClock,
+            interval,
+            leading: true,
+            trailing: true,
+            pending: None,
+            window: None,
+            stream_done: false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::StreamExt as _;
+    use crate::testing::ScriptedStream;
+    use crate::testing::Step;
+    use crate::testing::WakeCounter;
+    use futures::Stream;
+    use std::pin::Pin;
+    use std::task::Context;
+    use std::task::Poll;
+
+    fn poll_next<S: Stream + Unpin>(stream: &mut S, counter: &WakeCounter) -> Poll<Option<S::Item>> {
+        let waker = counter.waker();
+        Pin::new(stream).poll_next(&mut Context::from_waker(&waker))
+    }
 
     #[test]
     fn test_chain_ready() {
@@ -43,4 +441,87 @@ mod tests {
 
         assert_eq!(collected, vec![1, 2, 3, 4]);
     }
+
+    #[test]
+    fn test_chain_future_pending() {
+        let inner = ScriptedStream::new([Step::Ready(Ok(1)), Step::WakeAfter(1)]);
+        let script = inner.handle();
+        let (tx, rx) = futures::channel::oneshot::channel();
+        let mut stream = inner.chain_future(rx);
+        let counter = WakeCounter::new();
+
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(Some(Ok(1))));
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Pending);
+        script.advance();
+        assert_eq!(counter.count(), 1);
+
+        // The stream is exhausted, so the future is polled, which is pending until sent to.
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Pending);
+        tx.send(2).unwrap();
+        assert_eq!(counter.count(), 2);
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(Some(Ok(2))));
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(None));
+        assert_eq!(script.polls(), 3);
+    }
+
+    #[test]
+    fn test_chain_ready_if() {
+        let chained_stream = futures::stream::iter(vec![1, 2, 3]).chain_ready_if(true, 4);
+        let collected: Vec<_> = futures::executor::block_on_stream(chained_stream).collect();
+        assert_eq!(collected, vec![1, 2, 3, 4]);
+
+        let chained_stream = futures::stream::iter(vec![1, 2, 3]).chain_ready_if(false, 4);
+        let collected: Vec<_> = futures::executor::block_on_stream(chained_stream).collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_chain_ok() {
+        let chained_stream = futures::stream::iter(vec![Ok(1), Err("some error")]).chain_ok(3);
+        let collected: Vec<_> = futures::executor::block_on_stream(chained_stream).collect();
+        assert_eq!(collected, vec![Ok(1), Err("some error"), Ok(3)]);
+    }
+
+    #[derive(Debug, derive_more::Error, derive_more::Display)]
+    #[display("A mock error occurred")]
+    struct MockError;
+
+    #[test]
+    fn test_map_err_report() {
+        let stream = futures::stream::iter(vec![Ok(1), Err(MockError)]).map_err_report();
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected[0].as_ref().ok(), Some(&1));
+        assert_eq!(collected[1].as_ref().unwrap_err().to_string(), "A mock error occurred");
+    }
+
+    #[test]
+    fn test_prepend_ready() {
+        let initial_stream = futures::stream::iter(vec![1, 2, 3]);
+        let prepended_stream = initial_stream.prepend_ready(0);
+
+        let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+
+        assert_eq!(collected, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_prepend_future() {
+        let initial_stream = futures::stream::iter(vec![1, 2, 3]);
+        let prepended_stream = initial_stream.prepend_future(Box::pin(async { 0 }));
+
+        let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+
+        assert_eq!(collected, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_prepend_ready_if() {
+        let prepended_stream = futures::stream::iter(vec![1, 2, 3]).prepend_ready_if(true, 0);
+        let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+        assert_eq!(collected, vec![0, 1, 2, 3]);
+
+        let prepended_stream = futures::stream::iter(vec![1, 2, 3]).prepend_ready_if(false, 0);
+        let collected: Vec<_> = futures::executor::block_on_stream(prepended_stream).collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
 }
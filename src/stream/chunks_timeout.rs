@@ -0,0 +1,210 @@
+use crate::timer::Clock;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that buffers the items of an inner stream into chunks, emitting a chunk
+    /// when it is full or when a duration has passed since its first item was buffered.
+    #[derive(Debug)]
+    pub struct ChunksTimeout<S, C>
+    where
+        S: Stream,
+        C: Clock,
+    {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) clock: C,
+        pub(crate) items: Vec<S::Item>,
+        pub(crate) max_len: usize,
+        pub(crate) max_wait: Duration,
+        pub(crate) sleep: Option<C::Sleep>,
+        pub(crate) done: bool,
+    }
+}
+
+impl<S, C> ChunksTimeout<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    /// Uses the given clock to measure time, e.g. a [`MockClock`](crate::timer::MockClock) in
+    /// tests.
+    ///
+    /// If items are already buffered, the deadline of their chunk restarts on the new clock.
+    pub fn with_clock<C2>(self, clock: C2) -> ChunksTimeout<S, C2>
+    where
+        C2: Clock,
+    {
+        let sleep = (!self.items.is_empty()).then(|| clock.sleep_until(clock.now() + self.max_wait));
+        ChunksTimeout {
+            stream: self.stream,
+            clock,
+            items: self.items,
+            max_len: self.max_len,
+            max_wait: self.max_wait,
+            sleep,
+            done: self.done,
+        }
+    }
+}
+
+impl<S, C> Stream for ChunksTimeout<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.items.is_empty() {
+                        *this.sleep = Some(this.clock.sleep_until(this.clock.now() + *this.max_wait));
+                    }
+                    this.items.push(item);
+                    if this.items.len() >= *this.max_len {
+                        *this.sleep = None;
+                        return Poll::Ready(Some(std::mem::take(this.items)));
+                    }
+                }
+                Poll::Ready(None) => {
+                    *this.done = true;
+                    *this.sleep = None;
+                    if this.items.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    return Poll::Ready(Some(std::mem::take(this.items)));
+                }
+                Poll::Pending => {
+                    if let Some(sleep) = this.sleep.as_mut()
+                        && Pin::new(sleep).poll(cx).is_ready()
+                    {
+                        *this.sleep = None;
+                        return Poll::Ready(Some(std::mem::take(this.items)));
+                    }
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+
+        let buffered = self.items.len();
+        let (lower, upper) = self.stream.size_hint();
+        let lower = lower.saturating_add(buffered).div_ceil(self.max_len);
+        let upper = upper.and_then(|upper| upper.checked_add(buffered));
+        (lower, upper)
+    }
+}
+
+impl<S, C> FusedStream for ChunksTimeout<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+    use futures::stream::FusedStream as _;
+    use std::time::Duration;
+
+    #[test]
+    fn test_chunks_timeout_full() {
+        let mut stream = futures::stream::iter(1..=5).chunks_timeout(2, Duration::from_secs(60));
+        assert_eq!(futures::Stream::size_hint(&stream), (3, Some(5)));
+        let collected: Vec<_> = futures::executor::block_on_stream(&mut stream).collect();
+        assert_eq!(collected, vec![vec![1, 2], vec![3, 4], vec![5]]);
+        assert!(stream.is_terminated());
+    }
+
+    #[test]
+    fn test_chunks_timeout_deadline() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.chunks_timeout(10, Duration::from_millis(20)).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+
+        // The deadline runs from the first item of the chunk.
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(vec![1, 2])));
+
+        tx.unbounded_send(3).unwrap();
+        drop(tx);
+        assert_eq!(stream.next().now_or_never(), Some(Some(vec![3])));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn test_chunks_timeout_with_clock_rearms_deadline() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.chunks_timeout(10, Duration::from_millis(20));
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+
+        let clock = MockClock::new();
+        let mut stream = stream.with_clock(clock.clone());
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(20));
+        assert_eq!(stream.next().now_or_never(), Some(Some(vec![1])));
+    }
+
+    #[test]
+    fn test_chunks_timeout_unbounded_len() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.chunks_timeout(usize::MAX, Duration::from_millis(20)).with_clock(clock.clone());
+
+        for item in 1..=3 {
+            tx.unbounded_send(item).unwrap();
+        }
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(20));
+        assert_eq!(stream.next().now_or_never(), Some(Some(vec![1, 2, 3])));
+
+        tx.unbounded_send(4).unwrap();
+        drop(tx);
+        assert_eq!(stream.next().now_or_never(), Some(Some(vec![4])));
+    }
+
+    #[test]
+    fn test_chunks_timeout_empty() {
+        let stream = futures::stream::iter(Vec::<i32>::new()).chunks_timeout(2, Duration::from_secs(60));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert!(collected.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "max_len must be greater than zero")]
+    fn test_chunks_timeout_zero_len() {
+        let _ = futures::stream::iter(vec![1]).chunks_timeout(0, Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,58 @@
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that collects the `Ok` values of a stream producing `Result<T, E>` into a
+    /// collection, accumulating every error rather than stopping at the first one.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct CollectAll<S, C, E> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) collection: C,
+        pub(crate) errors: Vec<E>,
+    }
+}
+
+impl<S, C, T, E> Future for CollectAll<S, C, E>
+where
+    S: Stream<Item = Result<T, E>>,
+    C: Default + Extend<T>,
+{
+    type Output = Result<C, Vec<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(Ok(t)) => this.collection.extend(Some(t)),
+                Some(Err(err)) => this.errors.push(err),
+                None if this.errors.is_empty() => return Poll::Ready(Ok(std::mem::take(this.collection))),
+                None => return Poll::Ready(Err(std::mem::take(this.errors))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_collect_all_ok() {
+        let stream = futures::stream::iter(vec![Ok::<_, &str>(1), Ok(2)]);
+        let result: Result<Vec<_>, _> = futures::executor::block_on(stream.collect_all());
+        assert_eq!(result, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_collect_all_accumulates_every_error() {
+        let stream = futures::stream::iter(vec![Ok(1), Err("a"), Ok(3), Err("b")]);
+        let result: Result<Vec<_>, _> = futures::executor::block_on(stream.collect_all());
+        assert_eq!(result, Err(vec!["a", "b"]));
+    }
+}
@@ -0,0 +1,222 @@
+use crate::timer::Clock;
+use crate::timer::MAX_ITEMS_PER_POLL;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that emits the latest item of an inner stream once no new item has
+    /// arrived for a duration.
+    #[derive(Debug)]
+    pub struct Debounce<S, C>
+    where
+        S: Stream,
+        C: Clock,
+    {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) clock: C,
+        pub(crate) duration: Duration,
+        pub(crate) pending: Option<S::Item>,
+        pub(crate) sleep: Option<C::Sleep>,
+        pub(crate) stream_done: bool,
+    }
+}
+
+impl<S, C> Debounce<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    /// Uses the given clock to measure time, e.g. a [`MockClock`](crate::timer::MockClock) in
+    /// tests.
+    ///
+    /// If an item is already waiting to be emitted, its duration restarts on the new clock.
+    pub fn with_clock<C2>(self, clock: C2) -> Debounce<S, C2>
+    where
+        C2: Clock,
+    {
+        let sleep = self.pending.is_some().then(|| clock.sleep_until(clock.now() + self.duration));
+        Debounce {
+            stream: self.stream,
+            clock,
+            duration: self.duration,
+            pending: self.pending,
+            sleep,
+            stream_done: self.stream_done,
+        }
+    }
+}
+
+impl<S, C> Stream for Debounce<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = MAX_ITEMS_PER_POLL;
+        while !*this.stream_done {
+            if budget == 0 {
+                // The inner stream may still be ready, so ask to be polled again right away.
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    *this.pending = Some(item);
+                    *this.sleep = Some(this.clock.sleep_until(this.clock.now() + *this.duration));
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if *this.stream_done {
+            // Flush the latest item without waiting when the inner stream has ended.
+            *this.sleep = None;
+            return Poll::Ready(this.pending.take());
+        }
+
+        if let Some(sleep) = this.sleep.as_mut()
+            && Pin::new(sleep).poll(cx).is_ready()
+        {
+            *this.sleep = None;
+            return Poll::Ready(this.pending.take());
+        }
+
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = usize::from(self.pending.is_some());
+        if self.stream_done {
+            return (buffered, Some(buffered));
+        }
+
+        let (lower, upper) = self.stream.size_hint();
+        (
+            usize::from(lower > 0 || buffered > 0),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<S, C> FusedStream for Debounce<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.pending.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::testing::WakeCounter;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+    use std::time::Duration;
+
+    #[test]
+    fn test_debounce() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.debounce(Duration::from_millis(10)).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+
+        clock.advance(Duration::from_millis(5));
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+
+        clock.advance(Duration::from_millis(9));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+        assert_eq!(stream.next().now_or_never(), None);
+    }
+
+    #[test]
+    fn test_debounce_flushes_at_end() {
+        let clock = MockClock::new();
+        let mut stream = futures::stream::iter(vec![1, 2, 3]).debounce(Duration::from_secs(60)).with_clock(clock);
+
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+        assert!(futures::stream::FusedStream::is_terminated(&stream));
+    }
+
+    #[test]
+    fn test_debounce_with_clock_rearms_pending_item() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.debounce(Duration::from_millis(10));
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+
+        let clock = MockClock::new();
+        let mut stream = stream.with_clock(clock.clone());
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+    }
+
+    #[test]
+    fn test_debounce_always_ready_yields() {
+        let clock = MockClock::new();
+        let mut stream = futures::stream::repeat(1).debounce(Duration::from_millis(10)).with_clock(clock.clone());
+        let counter = WakeCounter::new();
+        let waker = counter.waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+
+        // An inner stream that never pauses never lets the duration pass, but must not starve the task.
+        assert_eq!(stream.poll_next_unpin(&mut cx), std::task::Poll::Pending);
+        assert_eq!(counter.count(), 1);
+        clock.advance(Duration::from_millis(10));
+        let wakes = counter.count();
+        assert_eq!(stream.poll_next_unpin(&mut cx), std::task::Poll::Pending);
+        assert_eq!(counter.count(), wakes + 1);
+    }
+
+    #[test]
+    fn test_debounce_emits_after_burst() {
+        let clock = MockClock::new();
+        let burst = futures::stream::iter(0..100).chain(futures::stream::pending());
+        let mut stream = burst.debounce(Duration::from_millis(10)).with_clock(clock.clone());
+        let counter = WakeCounter::new();
+        let waker = counter.waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+
+        // The burst is drained over several polls, each of which asks to be polled again.
+        let mut polls = 0;
+        loop {
+            let wakes = counter.count();
+            assert_eq!(stream.poll_next_unpin(&mut cx), std::task::Poll::Pending);
+            polls += 1;
+            if counter.count() == wakes {
+                break;
+            }
+        }
+        assert!(polls > 1);
+
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.poll_next_unpin(&mut cx), std::task::Poll::Ready(Some(99)));
+    }
+}
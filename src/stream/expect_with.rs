@@ -0,0 +1,91 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that unwraps the `Ok` values of an inner stream producing
+    /// `Result<T, E>`, panicking on the first `Err` value.
+    #[derive(Debug)]
+    pub struct ExpectWith<S, F> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: Option<F>,
+    }
+}
+
+impl<S, F, M, T, E> Stream for ExpectWith<S, F>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+    E: std::error::Error,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| {
+            item.map(|res| match res {
+                Ok(t) => t,
+                Err(error) => {
+                    let msg = this.f.take().expect("ExpectWith polled after panicking")();
+                    panic!("{}: {:?}", msg.as_ref(), crate::report::Report { error })
+                }
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, F, M, T, E> FusedStream for ExpectWith<S, F>
+where
+    S: FusedStream<Item = Result<T, E>>,
+    F: FnOnce() -> M,
+    M: AsRef<str>,
+    E: std::error::Error,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use derive_more::Display;
+    use derive_more::Error;
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock error occurred")]
+    struct MockError(MockSubError);
+
+    #[derive(Debug, Error, Display)]
+    #[display("A mock sub error occurred")]
+    struct MockSubError;
+
+    #[test]
+    fn test_expect_with_ok() {
+        let stream = futures::stream::iter(vec![Ok::<_, MockError>(1), Ok(2)]).expect_with(|| "Shouldn't see this");
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Custom report: A mock error occurred\n\nCaused by:\n      A mock sub error occurred")]
+    fn test_expect_with_err() {
+        let stream = futures::stream::iter(vec![Ok(1), Err(MockError(MockSubError))]).expect_with(|| "Custom report");
+        let _: Vec<_> = futures::executor::block_on_stream(stream).collect();
+    }
+
+    #[test]
+    fn test_expect_with_is_lazy() {
+        let stream = futures::stream::iter(vec![Ok(1), Err(MockError(MockSubError))]).expect_with(|| -> String { unreachable!() });
+        let mut stream = futures::executor::block_on_stream(stream);
+        assert_eq!(stream.next(), Some(1));
+    }
+}
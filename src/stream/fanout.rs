@@ -0,0 +1,422 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use futures::task::ArcWake;
+use std::collections::VecDeque;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+/// What a fanned out stream does when the buffer of a slow consumer is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FanoutPolicy {
+    /// Stop pulling items from the source stream until the slow consumer catches up.
+    Backpressure,
+    /// Drop the oldest buffered item of the slow consumer, counting it in the `lagged` method of
+    /// that consumer.
+    DropOldest,
+}
+
+/// One of several streams that receive a clone of every item of a shared source stream.
+///
+/// Each consumer has its own bounded buffer and can be polled independently of the others.
+/// Cloning a `Fanout` adds a new consumer, which receives the items pulled from the source
+/// after it was created.
+pub struct Fanout<S>
+where
+    S: Stream,
+{
+    shared: Arc<Mutex<Shared<S>>>,
+    wakers: Arc<Wakers>,
+    index: usize,
+}
+
+struct Shared<S>
+where
+    S: Stream,
+{
+    stream: Pin<Box<S>>,
+    consumers: Vec<Consumer<S::Item>>,
+    /// Slots of dropped consumers, reused by new consumers.
+    free: Vec<usize>,
+    capacity: usize,
+    policy: FanoutPolicy,
+    done: bool,
+}
+
+struct Consumer<T> {
+    queue: VecDeque<T>,
+    lagged: u64,
+    alive: bool,
+}
+
+/// Wakes every waiting consumer, so that no consumer misses a wake-up from the source stream,
+/// regardless of which consumer polled it last.
+#[derive(Default)]
+struct Wakers {
+    wakers: Mutex<Vec<Option<Waker>>>,
+}
+
+impl Wakers {
+    fn lock(&self) -> MutexGuard<'_, Vec<Option<Waker>>> {
+        self.wakers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn register(&self, index: usize, waker: &Waker) {
+        let mut wakers = self.lock();
+        match &mut wakers[index] {
+            Some(existing) if existing.will_wake(waker) => {}
+            slot => *slot = Some(waker.clone()),
+        }
+    }
+
+    fn wake_all(&self) {
+        let wakers: Vec<_> = self.lock().iter_mut().filter_map(Option::take).collect();
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+impl ArcWake for Wakers {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.wake_all();
+    }
+}
+
+impl<S> Fanout<S>
+where
+    S: Stream,
+{
+    pub(crate) fn new(stream: S, consumers: usize, capacity: usize, policy: FanoutPolicy) -> Vec<Self> {
+        assert!(capacity > 0, "capacity must be greater than zero");
+
+        let shared = Arc::new(Mutex::new(Shared {
+            stream: Box::pin(stream),
+            consumers: (0..consumers)
+                .map(|_| Consumer {
+                    queue: VecDeque::with_capacity(capacity),
+                    lagged: 0,
+                    alive: true,
+                })
+                .collect(),
+            free: Vec::new(),
+            capacity,
+            policy,
+            done: false,
+        }));
+        let wakers = Arc::new(Wakers {
+            wakers: Mutex::new(vec![None; consumers]),
+        });
+
+        (0..consumers)
+            .map(|index| Fanout {
+                shared: Arc::clone(&shared),
+                wakers: Arc::clone(&wakers),
+                index,
+            })
+            .collect()
+    }
+
+    /// Returns the number of items that were dropped for this consumer because its buffer was
+    /// full, with [`FanoutPolicy::DropOldest`].
+    pub fn lagged(&self) -> u64 {
+        self.lock().consumers[self.index].lagged
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Shared<S>> {
+        self.shared.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<S> Fanout<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    /// Polls for the next item under the lock, returning whether the other consumers should be
+    /// woken once the lock is released.
+    fn poll_shared(&self, cx: &mut Context<'_>) -> (Poll<Option<S::Item>>, bool) {
+        let mut shared = self.lock();
+        let shared = &mut *shared;
+
+        if let Some(item) = shared.consumers[self.index].queue.pop_front() {
+            // With backpressure, consumers waiting for buffer space may be able to continue.
+            return (Poll::Ready(Some(item)), shared.policy == FanoutPolicy::Backpressure);
+        }
+
+        if shared.done {
+            return (Poll::Ready(None), false);
+        }
+
+        self.wakers.register(self.index, cx.waker());
+
+        if shared.policy == FanoutPolicy::Backpressure
+            && shared
+                .consumers
+                .iter()
+                .any(|consumer| consumer.alive && consumer.queue.len() >= shared.capacity)
+        {
+            return (Poll::Pending, false);
+        }
+
+        let waker = futures::task::waker(Arc::clone(&self.wakers));
+        match shared.stream.as_mut().poll_next(&mut Context::from_waker(&waker)) {
+            Poll::Ready(Some(item)) => {
+                for (index, consumer) in shared.consumers.iter_mut().enumerate() {
+                    if index == self.index || !consumer.alive {
+                        continue;
+                    }
+                    if consumer.queue.len() >= shared.capacity {
+                        consumer.queue.pop_front();
+                        consumer.lagged += 1;
+                    }
+                    consumer.queue.push_back(item.clone());
+                }
+                (Poll::Ready(Some(item)), true)
+            }
+            Poll::Ready(None) => {
+                shared.done = true;
+                (Poll::Ready(None), true)
+            }
+            Poll::Pending => (Poll::Pending, false),
+        }
+    }
+}
+
+impl<S> Stream for Fanout<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let (poll, wake) = this.poll_shared(cx);
+        if wake {
+            // Wake without holding the lock, as woken consumers may poll right away.
+            this.wakers.wake_all();
+        }
+        poll
+    }
+}
+
+impl<S> FusedStream for Fanout<S>
+where
+    S: Stream,
+    S::Item: Clone,
+{
+    fn is_terminated(&self) -> bool {
+        let shared = self.lock();
+        shared.done && shared.consumers[self.index].queue.is_empty()
+    }
+}
+
+impl<S> Clone for Fanout<S>
+where
+    S: Stream,
+{
+    fn clone(&self) -> Self {
+        let mut shared = self.lock();
+        let index = match shared.free.pop() {
+            Some(index) => {
+                // The slot was reset when its previous consumer was dropped.
+                shared.consumers[index].alive = true;
+                index
+            }
+            None => {
+                let capacity = shared.capacity;
+                shared.consumers.push(Consumer {
+                    queue: VecDeque::with_capacity(capacity),
+                    lagged: 0,
+                    alive: true,
+                });
+                self.wakers.lock().push(None);
+                shared.consumers.len() - 1
+            }
+        };
+
+        Fanout {
+            shared: Arc::clone(&self.shared),
+            wakers: Arc::clone(&self.wakers),
+            index,
+        }
+    }
+}
+
+impl<S> Drop for Fanout<S>
+where
+    S: Stream,
+{
+    fn drop(&mut self) {
+        let mut shared = self.lock();
+        let consumer = &mut shared.consumers[self.index];
+        consumer.alive = false;
+        consumer.queue.clear();
+        consumer.lagged = 0;
+        shared.free.push(self.index);
+        self.wakers.lock()[self.index] = None;
+        drop(shared);
+
+        // A dropped consumer no longer holds back the others.
+        self.wakers.wake_all();
+    }
+}
+
+impl<S> fmt::Debug for Fanout<S>
+where
+    S: Stream,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fanout").field("index", &self.index).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StreamExt as _;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn test_fanout_every_consumer_gets_every_item() {
+        let consumers = futures::stream::iter(vec![1, 2, 3]).fanout(2, 4, FanoutPolicy::Backpressure);
+        let [a, b]: [_; 2] = consumers.try_into().unwrap();
+
+        let a: Vec<_> = futures::executor::block_on_stream(a).collect();
+        let b: Vec<_> = futures::executor::block_on_stream(b).collect();
+        assert_eq!(a, vec![1, 2, 3]);
+        assert_eq!(b, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_fanout_backpressure() {
+        let consumers = futures::stream::iter(1..=5).fanout(2, 2, FanoutPolicy::Backpressure);
+        let [mut fast, mut slow]: [_; 2] = consumers.try_into().unwrap();
+
+        assert_eq!(fast.next().now_or_never(), Some(Some(1)));
+        assert_eq!(fast.next().now_or_never(), Some(Some(2)));
+        // The buffer of the slow consumer is full.
+        assert_eq!(fast.next().now_or_never(), None);
+
+        assert_eq!(slow.next().now_or_never(), Some(Some(1)));
+        assert_eq!(fast.next().now_or_never(), Some(Some(3)));
+        assert_eq!(slow.lagged(), 0);
+
+        drop(slow);
+        let rest: Vec<_> = futures::executor::block_on_stream(fast).collect();
+        assert_eq!(rest, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_fanout_drop_oldest() {
+        let consumers = futures::stream::iter(1..=5).fanout(2, 2, FanoutPolicy::DropOldest);
+        let [fast, slow]: [_; 2] = consumers.try_into().unwrap();
+
+        let fast: Vec<_> = futures::executor::block_on_stream(fast).collect();
+        assert_eq!(fast, vec![1, 2, 3, 4, 5]);
+
+        let mut slow = futures::executor::block_on_stream(slow);
+        assert_eq!(slow.next(), Some(4));
+        assert_eq!(slow.next(), Some(5));
+        assert_eq!(slow.next(), None);
+        assert_eq!(slow.into_inner().lagged(), 3);
+    }
+
+    #[test]
+    fn test_fanout_wakes_other_consumers() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let consumers = rx.fanout(2, 4, FanoutPolicy::Backpressure);
+        let [a, b]: [_; 2] = consumers.try_into().unwrap();
+
+        let b = std::thread::spawn(move || futures::executor::block_on_stream(b).collect::<Vec<_>>());
+        let a = std::thread::spawn(move || futures::executor::block_on_stream(a).collect::<Vec<_>>());
+        for item in 0..100 {
+            tx.unbounded_send(item).unwrap();
+        }
+        drop(tx);
+
+        assert_eq!(a.join().unwrap(), (0..100).collect::<Vec<_>>());
+        assert_eq!(b.join().unwrap(), (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_fanout_wakes_without_holding_lock() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let consumers = rx.fanout(2, 4, FanoutPolicy::Backpressure);
+        let [mut a, mut b]: [_; 2] = consumers.try_into().unwrap();
+
+        let probe = Arc::new(LockProbe {
+            shared: Arc::clone(&b.shared),
+            unlocked: AtomicBool::new(false),
+        });
+        let waker = futures::task::waker(Arc::clone(&probe));
+        assert_eq!(b.poll_next_unpin(&mut Context::from_waker(&waker)), Poll::Pending);
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(a.next().now_or_never(), Some(Some(1)));
+        assert!(probe.unlocked.load(Ordering::SeqCst));
+        assert_eq!(b.next().now_or_never(), Some(Some(1)));
+    }
+
+    /// A waker that checks whether the shared state is unlocked when it is woken.
+    struct LockProbe<S: Stream> {
+        shared: Arc<Mutex<Shared<S>>>,
+        unlocked: AtomicBool,
+    }
+
+    impl<S> ArcWake for LockProbe<S>
+    where
+        S: Stream + Send,
+        S::Item: Send,
+    {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            let unlocked = arc_self.shared.try_lock().is_ok();
+            arc_self.unlocked.store(unlocked, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_broadcast_reuses_dropped_slots() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut a = rx.broadcast(4, FanoutPolicy::DropOldest);
+
+        for item in 0..100 {
+            let mut b = a.clone();
+            tx.unbounded_send(item).unwrap();
+            assert_eq!(b.next().now_or_never(), Some(Some(item)));
+            assert_eq!(b.lagged(), 0);
+        }
+        assert_eq!(a.lock().consumers.len(), 2);
+
+        let rest: Vec<_> = std::iter::from_fn(|| a.next().now_or_never().flatten()).collect();
+        assert_eq!(rest, vec![96, 97, 98, 99]);
+        assert_eq!(a.lagged(), 96);
+    }
+
+    #[test]
+    fn test_broadcast_clone() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut a = rx.broadcast(4, FanoutPolicy::Backpressure);
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(a.next().now_or_never(), Some(Some(1)));
+
+        let mut b = a.clone();
+        tx.unbounded_send(2).unwrap();
+        drop(tx);
+        assert_eq!(b.next().now_or_never(), Some(Some(2)));
+        assert_eq!(b.next().now_or_never(), Some(None));
+        assert_eq!(a.next().now_or_never(), Some(Some(2)));
+        assert_eq!(a.next().now_or_never(), Some(None));
+    }
+}
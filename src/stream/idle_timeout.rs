@@ -0,0 +1,158 @@
+use crate::timer::Clock;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that ends the stream when the next item of an inner stream does not
+    /// arrive within a duration.
+    #[derive(Debug)]
+    pub struct IdleTimeout<S, C>
+    where
+        C: Clock,
+    {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) clock: C,
+        pub(crate) duration: Duration,
+        pub(crate) sleep: Option<C::Sleep>,
+        pub(crate) done: bool,
+        pub(crate) timed_out: bool,
+    }
+}
+
+impl<S, C> IdleTimeout<S, C>
+where
+    C: Clock,
+{
+    /// Returns `true` if the stream was ended because the idle timeout elapsed.
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /// Uses the given clock to measure time, e.g. a [`MockClock`](crate::timer::MockClock) in
+    /// tests.
+    ///
+    /// A deadline that is already running restarts on the new clock.
+    pub fn with_clock<C2>(self, clock: C2) -> IdleTimeout<S, C2>
+    where
+        C2: Clock,
+    {
+        // The deadline is armed again on the next poll.
+        IdleTimeout {
+            stream: self.stream,
+            clock,
+            duration: self.duration,
+            sleep: None,
+            done: self.done,
+            timed_out: self.timed_out,
+        }
+    }
+}
+
+impl<S, C> Stream for IdleTimeout<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.done {
+            return Poll::Ready(None);
+        }
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                *this.sleep = None;
+                return Poll::Ready(Some(item));
+            }
+            Poll::Ready(None) => {
+                *this.sleep = None;
+                *this.done = true;
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        let clock = &*this.clock;
+        let sleep = this.sleep.get_or_insert_with(|| clock.sleep_until(clock.now() + *this.duration));
+        match Pin::new(sleep).poll(cx) {
+            Poll::Ready(()) => {
+                *this.sleep = None;
+                *this.done = true;
+                *this.timed_out = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done { (0, Some(0)) } else { (0, self.stream.size_hint().1) }
+    }
+}
+
+impl<S, C> FusedStream for IdleTimeout<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+    use futures::stream::FusedStream as _;
+    use std::time::Duration;
+
+    #[test]
+    fn test_idle_timeout_natural_end() {
+        let mut stream = futures::stream::iter(vec![1, 2])
+            .idle_timeout(Duration::from_millis(10))
+            .with_clock(MockClock::new());
+        let collected: Vec<_> = futures::executor::block_on_stream(&mut stream).collect();
+        assert_eq!(collected, vec![1, 2]);
+        assert!(stream.is_terminated());
+        assert!(!stream.timed_out());
+    }
+
+    #[test]
+    fn test_idle_timeout_elapsed() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.idle_timeout(Duration::from_millis(20)).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        clock.advance(Duration::from_millis(19));
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(2)));
+
+        // The deadline restarted with the item.
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(19));
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+
+        assert!(stream.is_terminated());
+        assert!(stream.timed_out());
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+}
@@ -0,0 +1,57 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that calls a function with a reference to the `Err` values of an inner
+    /// stream producing `Result<T, E>`, passing every item through unchanged.
+    #[derive(Debug)]
+    pub struct InspectErr<S, F> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+    }
+}
+
+impl<S, F, T, E> Stream for InspectErr<S, F>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(&E),
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| item.map(|res| res.inspect_err(&mut *this.f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, F, T, E> FusedStream for InspectErr<S, F>
+where
+    S: FusedStream<Item = Result<T, E>>,
+    F: FnMut(&E),
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_inspect_err_basic() {
+        let mut seen = Vec::new();
+        let stream = futures::stream::iter(vec![Ok(1), Err("a"), Ok(3), Err("b")]).inspect_err(|err| seen.push(*err));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(1), Err("a"), Ok(3), Err("b")]);
+        assert_eq!(seen, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,60 @@
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that converts each item of a stream to a string and joins them into a single
+    /// string, separated by a separator.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct JoinAsStrings<S> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) separator: String,
+        pub(crate) output: Option<String>,
+    }
+}
+
+impl<S> Future for JoinAsStrings<S>
+where
+    S: Stream,
+    S::Item: ToString,
+{
+    type Output = String;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => match this.output {
+                    Some(output) => {
+                        output.push_str(this.separator);
+                        output.push_str(&item.to_string());
+                    }
+                    None => *this.output = Some(item.to_string()),
+                },
+                None => return Poll::Ready(this.output.take().unwrap_or_default()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_join_as_strings() {
+        let stream = futures::stream::iter(vec![1, 2, 3]);
+        assert_eq!(futures::executor::block_on(stream.join_as_strings(", ")), "1, 2, 3");
+    }
+
+    #[test]
+    fn test_join_as_strings_empty() {
+        let stream = futures::stream::iter(Vec::<i32>::new());
+        assert_eq!(futures::executor::block_on(stream.join_as_strings(", ")), "");
+    }
+}
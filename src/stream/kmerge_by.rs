@@ -0,0 +1,174 @@
+use crate::merge::HeadTail;
+use crate::merge::MergeHeap;
+use crate::merge::MergeOrder;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that merges any number of sorted streams into one sorted stream, using a
+    /// binary heap of the next item of each stream.
+    ///
+    /// An item is only emitted once the next item of every stream is known, or the stream has
+    /// ended. For equal items, the items of earlier streams are emitted first.
+    pub struct KMergeBy<S, F>
+    where
+        S: Stream,
+    {
+        heap: MergeHeap<S::Item, S, F>,
+        // The streams whose next item is not known yet, with their source index.
+        waiting: Vec<(usize, S)>,
+    }
+}
+
+impl<S, F> KMergeBy<S, F>
+where
+    S: Stream + Unpin,
+    F: MergeOrder<S::Item>,
+{
+    pub(crate) fn new(streams: impl IntoIterator<Item = S>, cmp: F) -> Self {
+        KMergeBy {
+            heap: MergeHeap::new(cmp),
+            waiting: streams.into_iter().enumerate().collect(),
+        }
+    }
+}
+
+impl<S, F> std::fmt::Debug for KMergeBy<S, F>
+where
+    S: Stream + std::fmt::Debug,
+    S::Item: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KMergeBy")
+            .field("heap", &self.heap.entries())
+            .field("waiting", &self.waiting)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, F> Stream for KMergeBy<S, F>
+where
+    S: Stream + Unpin,
+    F: MergeOrder<S::Item>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let mut index = 0;
+        while let Some((source, stream)) = this.waiting.get_mut(index) {
+            match Pin::new(stream).poll_next(cx) {
+                Poll::Ready(Some(head)) => {
+                    let source = *source;
+                    let (_, tail) = this.waiting.swap_remove(index);
+                    this.heap.push(HeadTail { head, tail, source });
+                }
+                Poll::Ready(None) => {
+                    this.waiting.swap_remove(index);
+                }
+                Poll::Pending => index += 1,
+            }
+        }
+        if !this.waiting.is_empty() {
+            return Poll::Pending;
+        }
+
+        match this.heap.pop() {
+            Some(HeadTail { head, tail, source }) => {
+                this.waiting.push((source, tail));
+                Poll::Ready(Some(head))
+            }
+            None => Poll::Ready(None),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let heads = self.heap.entries().iter().map(|entry| {
+            let (lower, upper) = entry.tail.size_hint();
+            (lower.saturating_add(1), upper.and_then(|upper| upper.checked_add(1)))
+        });
+        let waiting = self.waiting.iter().map(|(_, stream)| stream.size_hint());
+        heads.chain(waiting).fold((0, Some(0)), |(lower, upper), (stream_lower, stream_upper)| {
+            let upper = upper.zip(stream_upper).and_then(|(upper, stream_upper)| upper.checked_add(stream_upper));
+            (lower.saturating_add(stream_lower), upper)
+        })
+    }
+}
+
+impl<S, F> FusedStream for KMergeBy<S, F>
+where
+    S: Stream + Unpin,
+    F: MergeOrder<S::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.heap.entries().is_empty() && self.waiting.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::testing::ScriptedStream;
+    use crate::testing::Step;
+    use futures::FutureExt as _;
+    use futures::Stream as _;
+    use futures::StreamExt as _;
+
+    #[test]
+    fn test_kmerge_by_basic() {
+        let mut shards = vec![vec![1, 4, 7], vec![2, 5, 8], vec![], vec![3, 6, 9]].into_iter().map(futures::stream::iter);
+        let first = shards.next().unwrap();
+        let stream = first.kmerge_by(shards, |a: &i32, b: &i32| a.cmp(b));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, (1..=9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_kmerge_by_stable() {
+        let first = futures::stream::iter(vec![(1, 'a'), (2, 'a')]);
+        let others = [futures::stream::iter(vec![(1, 'b')]), futures::stream::iter(vec![(1, 'c'), (2, 'c')])];
+        let stream = first.kmerge_by(others, |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![(1, 'a'), (1, 'b'), (1, 'c'), (2, 'a'), (2, 'c')]);
+    }
+
+    #[test]
+    fn test_kmerge_by_waits_for_every_head() {
+        let first = ScriptedStream::new([Step::Ready(2), Step::Ready(4)]);
+        let others = [
+            ScriptedStream::new([Step::Pending, Step::Ready(3)]),
+            ScriptedStream::new([Step::Ready(1), Step::Pending, Step::Ready(5)]),
+        ];
+        let mut stream = first.kmerge_by(others, |a: &i32, b: &i32| a.cmp(b));
+
+        assert_eq!(stream.next().now_or_never(), None);
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), None);
+        assert_eq!(stream.next().now_or_never(), Some(Some(2)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(4)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(5)));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+        assert!(futures::stream::FusedStream::is_terminated(&stream));
+    }
+
+    #[test]
+    fn test_kmerge_res_by() {
+        let first = futures::stream::iter(vec![Ok(1), Ok(4)]);
+        let others = [futures::stream::iter(vec![Ok(2), Err("b"), Ok(5)]), futures::stream::iter(vec![Ok(3), Ok(6)])];
+        let stream = first.kmerge_res_by(others, |a: &i32, b: &i32| a.cmp(b));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(1), Ok(2), Err("b"), Ok(3), Ok(4), Ok(5), Ok(6)]);
+    }
+
+    #[test]
+    fn test_kmerge_by_size_hint() {
+        let others = [futures::stream::iter(vec![3])];
+        let stream = futures::stream::iter(vec![1, 2]).kmerge_by(others, |a: &i32, b: &i32| a.cmp(b));
+        assert_eq!(stream.size_hint(), (3, Some(3)));
+    }
+}
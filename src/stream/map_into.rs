@@ -0,0 +1,60 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that converts the items of an inner stream
+    /// producing `T` into `U` using the `Into` trait.
+    #[derive(Debug)]
+    pub struct MapInto<S, U> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) _marker: std::marker::PhantomData<U>,
+    }
+}
+
+impl<S, T, U> Stream for MapInto<S, U>
+where
+    S: Stream<Item = T>,
+    T: Into<U>,
+{
+    type Item = U;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx).map(|item| item.map(Into::into))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, T, U> FusedStream for MapInto<S, U>
+where
+    S: FusedStream<Item = T>,
+    T: Into<U>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_map_into_basic() {
+        let stream = futures::stream::iter(vec![1_u8, 3_u8]).map_into::<u32>();
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![1_u32, 3_u32]);
+    }
+
+    #[test]
+    fn test_map_into_size_hint() {
+        let stream = futures::stream::iter(vec![10_u8, 20, 30]).map_into::<u32>();
+        assert_eq!(futures::Stream::size_hint(&stream), (3, Some(3)));
+    }
+}
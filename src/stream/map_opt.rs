@@ -0,0 +1,71 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that applies a function to the items of an inner stream
+    /// producing `Option<T>`, transforming the `Some` values.
+    #[derive(Debug)]
+    pub struct MapOpt<S, F> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+    }
+}
+
+impl<S, F, T, U> Stream for MapOpt<S, F>
+where
+    S: Stream<Item = Option<T>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Option<U>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| item.map(|opt| opt.map(&mut *this.f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, F, T, U> FusedStream for MapOpt<S, F>
+where
+    S: FusedStream<Item = Option<T>>,
+    F: FnMut(T) -> U,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use futures::stream::FusedStream as _;
+
+    #[test]
+    fn test_map_opt_basic() {
+        let stream = futures::stream::iter(vec![Some(1), None, Some(3)]).map_opt(|x| x * 2);
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Some(2), None, Some(6)]);
+    }
+
+    #[test]
+    fn test_map_opt_size_hint() {
+        let stream = futures::stream::iter(vec![Some(1), None]).map_opt(|x| x * 2);
+        assert_eq!(futures::Stream::size_hint(&stream), (2, Some(2)));
+    }
+
+    #[test]
+    fn test_map_opt_fused() {
+        let mut stream = futures::StreamExt::fuse(futures::stream::iter(vec![Some(1)])).map_opt(|x| x * 2);
+        assert!(!stream.is_terminated());
+        let collected: Vec<_> = futures::executor::block_on_stream(&mut stream).collect();
+        assert_eq!(collected, vec![Some(2)]);
+        assert!(stream.is_terminated());
+    }
+}
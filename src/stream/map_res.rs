@@ -0,0 +1,55 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that applies a function to the items of an inner stream
+    /// producing `Result<T, E>`, transforming the `Ok` values.
+    #[derive(Debug)]
+    pub struct MapRes<S, F> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+    }
+}
+
+impl<S, F, T, U, E> Stream for MapRes<S, F>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| item.map(|res| res.map(&mut *this.f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, F, T, U, E> FusedStream for MapRes<S, F>
+where
+    S: FusedStream<Item = Result<T, E>>,
+    F: FnMut(T) -> U,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_map_res_basic() {
+        let stream = futures::stream::iter(vec![Ok(1), Err("some error"), Ok(3)]).map_res(|x| x * 2);
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(2), Err("some error"), Ok(6)]);
+    }
+}
@@ -0,0 +1,143 @@
+use futures::Stream;
+use futures::future::Either;
+use futures::stream::Fuse;
+use futures::stream::FusedStream;
+use futures::stream::FuturesOrdered;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+type Queued<Fut, U, E> = Either<futures::future::Map<Fut, fn(U) -> Result<U, E>>, futures::future::Ready<Result<U, E>>>;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that applies an async function to the `Ok` values of an inner stream
+    /// producing `Result<T, E>`, running up to a limit of futures concurrently while preserving
+    /// the order of the items.
+    #[derive(Debug)]
+    pub struct MapResConcurrent<S, F, Fut, U, E>
+    where
+        Fut: Future<Output = U>,
+    {
+        #[pin]
+        pub(crate) stream: Fuse<S>,
+        pub(crate) f: F,
+        pub(crate) queue: FuturesOrdered<Queued<Fut, U, E>>,
+        pub(crate) limit: usize,
+    }
+}
+
+impl<S, F, Fut, T, U, E> Stream for MapResConcurrent<S, F, Fut, U, E>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = U>,
+{
+    type Item = Result<U, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        while this.queue.len() < *this.limit {
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(t))) => {
+                    let fut = futures::FutureExt::map((this.f)(t), Ok as fn(U) -> Result<U, E>);
+                    this.queue.push_back(Either::Left(fut));
+                }
+                Poll::Ready(Some(Err(err))) => this.queue.push_back(Either::Right(futures::future::ready(Err(err)))),
+                Poll::Ready(None) | Poll::Pending => break,
+            }
+        }
+
+        match futures::StreamExt::poll_next_unpin(this.queue, cx) {
+            Poll::Ready(Some(res)) => Poll::Ready(Some(res)),
+            Poll::Ready(None) if this.stream.is_done() => Poll::Ready(None),
+            Poll::Ready(None) | Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued = self.queue.len();
+        let (lower, upper) = self.stream.size_hint();
+        (lower.saturating_add(queued), upper.and_then(|upper| upper.checked_add(queued)))
+    }
+}
+
+impl<S, F, Fut, T, U, E> FusedStream for MapResConcurrent<S, F, Fut, U, E>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = U>,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_done() && self.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use std::sync::Arc;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    #[test]
+    fn test_map_res_concurrent_preserves_order() {
+        let stream = futures::stream::iter(vec![Ok(1), Err("a"), Ok(3), Err("b"), Ok(5)]).map_res_concurrent(2, |x| async move { x * 2 });
+        let collected: Vec<_> = futures::executor::block_on_stream(Box::pin(stream)).collect();
+        assert_eq!(collected, vec![Ok(2), Err("a"), Ok(6), Err("b"), Ok(10)]);
+    }
+
+    #[test]
+    fn test_map_res_concurrent_out_of_order_completion() {
+        let (tx1, rx1) = futures::channel::oneshot::channel::<i32>();
+        let (tx2, rx2) = futures::channel::oneshot::channel::<i32>();
+        let receivers = std::sync::Mutex::new(vec![rx2, rx1]);
+        let stream = futures::stream::iter(vec![Ok::<_, &str>(1), Ok(2)]).map_res_concurrent(2, |_| {
+            let rx = receivers.lock().unwrap().pop().unwrap();
+            async move { rx.await.unwrap() }
+        });
+        let mut stream = Box::pin(stream);
+
+        let mut next = futures::StreamExt::next(&mut stream);
+        assert!(futures::FutureExt::now_or_never(&mut next).is_none());
+        tx2.send(20).unwrap();
+        assert!(futures::FutureExt::now_or_never(&mut next).is_none());
+        tx1.send(10).unwrap();
+        assert_eq!(futures::FutureExt::now_or_never(&mut next), Some(Some(Ok(10))));
+        assert_eq!(futures::executor::block_on(futures::StreamExt::next(&mut stream)), Some(Ok(20)));
+    }
+
+    async fn yield_now() {
+        let mut yielded = false;
+        futures::future::poll_fn(|cx| {
+            if yielded {
+                return std::task::Poll::Ready(());
+            }
+            yielded = true;
+            cx.waker().wake_by_ref();
+            std::task::Poll::Pending
+        })
+        .await
+    }
+
+    #[test]
+    fn test_map_res_concurrent_limit() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let stream = futures::stream::iter((0..10).map(Ok::<_, ()>)).map_res_concurrent(3, |x| {
+            let running = running.clone();
+            let max_running = max_running.clone();
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now, Ordering::SeqCst);
+                yield_now().await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                x
+            }
+        });
+        let collected: Vec<_> = futures::executor::block_on_stream(Box::pin(stream)).collect();
+        assert_eq!(collected, (0..10).map(Ok).collect::<Vec<_>>());
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,55 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that applies a function to the items of an inner stream
+    /// producing `Result<T, E>`, transforming the `Err` values.
+    #[derive(Debug)]
+    pub struct MapResErr<S, F> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+    }
+}
+
+impl<S, F, T, E, U> Stream for MapResErr<S, F>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    type Item = Result<T, U>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream.poll_next(cx).map(|item| item.map(|res| res.map_err(&mut *this.f)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S, F, T, E, U> FusedStream for MapResErr<S, F>
+where
+    S: FusedStream<Item = Result<T, E>>,
+    F: FnMut(E) -> U,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_map_res_err_basic() {
+        let stream = futures::stream::iter(vec![Ok(1), Err("unexpected thing happened"), Ok(3)]).map_res_err(|err| format!("Oh no: {err}"));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(1), Err("Oh no: unexpected thing happened".to_string()), Ok(3)]);
+    }
+}
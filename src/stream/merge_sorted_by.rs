@@ -0,0 +1,129 @@
+use crate::merge::MergeOrder;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that merges two sorted streams into one sorted stream.
+    ///
+    /// An item is only emitted once the next item of both streams is known, or a stream has
+    /// ended. For equal items, the items of the first stream are emitted first.
+    #[derive(Debug)]
+    pub struct MergeSortedBy<S1, S2, F>
+    where
+        S1: Stream,
+    {
+        #[pin]
+        pub(crate) left: S1,
+        #[pin]
+        pub(crate) right: S2,
+        pub(crate) left_head: Option<S1::Item>,
+        pub(crate) right_head: Option<S1::Item>,
+        pub(crate) left_done: bool,
+        pub(crate) right_done: bool,
+        pub(crate) cmp: F,
+    }
+}
+
+impl<S1, S2, F> Stream for MergeSortedBy<S1, S2, F>
+where
+    S1: Stream,
+    S2: Stream<Item = S1::Item>,
+    F: MergeOrder<S1::Item>,
+{
+    type Item = S1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if this.left_head.is_none() && !*this.left_done {
+            match this.left.poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.left_head = Some(item),
+                Poll::Ready(None) => *this.left_done = true,
+                Poll::Pending => {}
+            }
+        }
+        if this.right_head.is_none() && !*this.right_done {
+            match this.right.poll_next(cx) {
+                Poll::Ready(Some(item)) => *this.right_head = Some(item),
+                Poll::Ready(None) => *this.right_done = true,
+                Poll::Pending => {}
+            }
+        }
+
+        match (this.left_head.as_ref(), this.right_head.as_ref()) {
+            (Some(left), Some(right)) => {
+                if this.cmp.compare(right, left).is_lt() {
+                    Poll::Ready(this.right_head.take())
+                } else {
+                    Poll::Ready(this.left_head.take())
+                }
+            }
+            (Some(_), None) if *this.right_done => Poll::Ready(this.left_head.take()),
+            (None, Some(_)) if *this.left_done => Poll::Ready(this.right_head.take()),
+            (None, None) if *this.left_done && *this.right_done => Poll::Ready(None),
+            _ => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = usize::from(self.left_head.is_some()) + usize::from(self.right_head.is_some());
+        let (left_lower, left_upper) = if self.left_done { (0, Some(0)) } else { self.left.size_hint() };
+        let (right_lower, right_upper) = if self.right_done { (0, Some(0)) } else { self.right.size_hint() };
+        let upper = left_upper
+            .zip(right_upper)
+            .and_then(|(left, right)| left.checked_add(right)?.checked_add(buffered));
+        (left_lower.saturating_add(right_lower).saturating_add(buffered), upper)
+    }
+}
+
+impl<S1, S2, F> FusedStream for MergeSortedBy<S1, S2, F>
+where
+    S1: Stream,
+    S2: Stream<Item = S1::Item>,
+    F: MergeOrder<S1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        self.left_done && self.right_done && self.left_head.is_none() && self.right_head.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::testing::ScriptedStream;
+    use crate::testing::Step;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+
+    #[test]
+    fn test_merge_sorted_by_basic() {
+        let stream = futures::stream::iter(vec![1, 4, 5]).merge_sorted_by(futures::stream::iter(vec![2, 3, 6]), |a: &i32, b: &i32| a.cmp(b));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_merge_sorted_by_waits_for_both_heads() {
+        let left = ScriptedStream::new([Step::Ready(2), Step::Ready(3)]);
+        let right = ScriptedStream::new([Step::Pending, Step::Ready(1)]);
+        let mut stream = left.merge_sorted_by(right, |a: &i32, b: &i32| a.cmp(b));
+
+        assert_eq!(stream.next().now_or_never(), None);
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(2)));
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn test_merge_sorted_res_by() {
+        let left = futures::stream::iter(vec![Ok(1), Err("a"), Ok(5)]);
+        let right = futures::stream::iter(vec![Ok(2), Ok(6)]);
+        let stream = left.merge_sorted_res_by(right, |a: &i32, b: &i32| a.cmp(b));
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(1), Err("a"), Ok(2), Ok(5), Ok(6)]);
+    }
+}
@@ -0,0 +1,50 @@
+use futures::Stream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+
+pin_project_lite::pin_project! {
+    /// A future that partitions a stream producing `Result<T, E>` into the `Ok` values and the
+    /// `Err` values.
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct PartitionResults<S, T, E> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) oks: Vec<T>,
+        pub(crate) errs: Vec<E>,
+    }
+}
+
+impl<S, T, E> Future for PartitionResults<S, T, E>
+where
+    S: Stream<Item = Result<T, E>>,
+{
+    type Output = (Vec<T>, Vec<E>);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(Ok(t)) => this.oks.push(t),
+                Some(Err(err)) => this.errs.push(err),
+                None => return Poll::Ready((std::mem::take(this.oks), std::mem::take(this.errs))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+
+    #[test]
+    fn test_partition_results() {
+        let stream = futures::stream::iter(vec![Ok(1), Err("a"), Ok(3), Err("b")]);
+        let (oks, errs) = futures::executor::block_on(stream.partition_results());
+        assert_eq!(oks, vec![1, 3]);
+        assert_eq!(errs, vec!["a", "b"]);
+    }
+}
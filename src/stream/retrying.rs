@@ -0,0 +1,516 @@
+use crate::timer::Clock;
+use crate::timer::SystemClock;
+use futures::Stream;
+use futures::channel::mpsc;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::task::ready;
+use std::time::Duration;
+
+/// Creates a stream that is built by `factory`, and is transparently recreated with exponential
+/// backoff whenever it ends or yields a fatal error.
+///
+/// `factory` returns a future of a fresh stream of `Result<T, E>`, so that connecting can be
+/// asynchronous. For a stream that is available right away, return it in [`std::future::ready`],
+/// as in the example below. A factory that returns a stream directly is not accepted as is,
+/// because a type can be both a stream and a future, so the two forms cannot be told apart. The
+/// `Ok` values of every stream are passed
+/// through. By default, every `Err` value is fatal: the stream is dropped and recreated, and the
+/// error is reported on the [`events`](Retrying::events) side channel instead of being passed
+/// through. Call [`retry_if`](Retrying::retry_if) to pass non-fatal errors through instead.
+///
+/// The backoff is reset once a recreated stream yields an `Ok` value. If the policy has a maximum
+/// number of retries and it is exceeded, the returned stream ends.
+///
+/// The returned stream waits between retries with the [system clock](crate::timer::SystemClock).
+/// Call [`with_clock`](Retrying::with_clock) on it to use another [`Clock`], such as a
+/// [`MockClock`](crate::timer::MockClock) in tests.
+///
+/// # Examples
+///
+/// ```
+/// use futures::FutureExt as _;
+/// use futures::StreamExt as _;
+/// use std::time::Duration;
+/// use type_toppings::stream::RetryPolicy;
+/// use type_toppings::timer::MockClock;
+///
+/// let clock = MockClock::new();
+/// let mut connections = 0;
+/// let mut stream = type_toppings::stream::retrying(
+///     move || {
+///         connections += 1;
+///         std::future::ready(futures::stream::iter(vec![Ok(connections), Err("connection lost")]))
+///     },
+///     RetryPolicy::exponential(Duration::from_millis(100)),
+/// )
+/// .with_clock(clock.clone());
+/// let mut events = stream.events();
+///
+/// assert_eq!(stream.next().now_or_never(), Some(Some(Ok(1))));
+/// assert_eq!(stream.next().now_or_never(), None);
+/// assert!(events.next().now_or_never().is_some());
+///
+/// clock.advance(Duration::from_millis(100));
+/// assert_eq!(stream.next().now_or_never(), Some(Some(Ok(2))));
+/// ```
+pub fn retrying<F, Fut, S, T, E>(factory: F, policy: RetryPolicy) -> Retrying<F, Fut, S, E, RetryAll<E>, SystemClock>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = S>,
+    S: Stream<Item = Result<T, E>>,
+{
+    Retrying {
+        factory,
+        policy,
+        retry_if: |_| true,
+        clock: SystemClock,
+        state: State::Start,
+        attempt: 0,
+        events: None,
+    }
+}
+
+/// The default predicate of a [`retrying`] stream, which recreates the stream on every error.
+pub type RetryAll<E> = fn(&E) -> bool;
+
+/// How a [`retrying`] stream backs off between retries.
+///
+/// The delay before the first retry is the initial backoff, and it is multiplied for every
+/// consecutive retry, up to a maximum.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    max_retries: Option<usize>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the given initial backoff, which is doubled for every consecutive
+    /// retry, up to 30 seconds, without limiting the number of retries.
+    pub fn exponential(initial_backoff: Duration) -> Self {
+        RetryPolicy {
+            initial_backoff,
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            max_retries: None,
+        }
+    }
+
+    /// Sets the maximum backoff.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the factor that the backoff is multiplied by for every consecutive retry.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the maximum number of consecutive retries, after which the stream ends.
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Returns the backoff before the given consecutive retry, starting at 1.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let secs = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        if secs.is_nan() {
+            return self.max_backoff;
+        }
+        Duration::try_from_secs_f64(secs).map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+impl Default for RetryPolicy {
+    /// An exponential policy with an initial backoff of 100 milliseconds.
+    fn default() -> Self {
+        Self::exponential(Duration::from_millis(100))
+    }
+}
+
+/// An event reported on the [`events`](Retrying::events) side channel of a [`retrying`] stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryEvent<E> {
+    /// The stream ended or failed, and will be recreated after `delay`.
+    Reconnecting {
+        /// The number of the consecutive retry, starting at 1.
+        attempt: usize,
+        /// The backoff before the stream is recreated.
+        delay: Duration,
+        /// Why the stream is recreated.
+        cause: RetryCause<E>,
+    },
+    /// The stream was recreated.
+    Reconnected {
+        /// The number of the consecutive retry, starting at 1.
+        attempt: usize,
+    },
+    /// The stream ended or failed after the maximum number of retries, so the retrying stream
+    /// ends.
+    GaveUp {
+        /// Why the stream would have been recreated.
+        cause: RetryCause<E>,
+    },
+}
+
+/// Why a [`retrying`] stream recreates its inner stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryCause<E> {
+    /// The stream ended.
+    Ended,
+    /// The stream yielded a fatal error.
+    Error(E),
+}
+
+pin_project_lite::pin_project! {
+    /// A stream that recreates its inner stream with exponential backoff, created with
+    /// [`retrying`].
+    pub struct Retrying<F, Fut, S, E, P, C>
+    where
+        C: Clock,
+    {
+        factory: F,
+        policy: RetryPolicy,
+        retry_if: P,
+        clock: C,
+        #[pin]
+        state: State<Fut, S, C::Sleep>,
+        attempt: usize,
+        events: Option<mpsc::UnboundedSender<RetryEvent<E>>>,
+    }
+}
+
+pin_project_lite::pin_project! {
+    #[project = StateProj]
+    enum State<Fut, S, Sleep> {
+        Start,
+        Connecting {
+            #[pin]
+            future: Fut,
+        },
+        Streaming {
+            #[pin]
+            stream: S,
+        },
+        Sleeping {
+            sleep: Sleep,
+        },
+        Done,
+    }
+}
+
+impl<F, Fut, S, E, P, C> Retrying<F, Fut, S, E, P, C>
+where
+    C: Clock,
+{
+    /// Returns a receiver of the [`RetryEvent`]s of this stream.
+    ///
+    /// Only the receiver returned by the latest call receives events. Events are dropped if there
+    /// is no receiver.
+    pub fn events(&mut self) -> mpsc::UnboundedReceiver<RetryEvent<E>> {
+        let (tx, rx) = mpsc::unbounded();
+        self.events = Some(tx);
+        rx
+    }
+
+    /// Only recreates the stream for the errors that `retry_if` returns `true` for, passing the
+    /// other errors through.
+    pub fn retry_if<P2>(self, retry_if: P2) -> Retrying<F, Fut, S, E, P2, C>
+    where
+        P2: FnMut(&E) -> bool,
+    {
+        Retrying {
+            factory: self.factory,
+            policy: self.policy,
+            retry_if,
+            clock: self.clock,
+            state: self.state,
+            attempt: self.attempt,
+            events: self.events,
+        }
+    }
+
+    /// Uses the given clock to wait between retries, e.g. a
+    /// [`MockClock`](crate::timer::MockClock) in tests.
+    pub fn with_clock<C2>(self, clock: C2) -> Retrying<F, Fut, S, E, P, C2>
+    where
+        C2: Clock,
+    {
+        let state = match self.state {
+            State::Start => State::Start,
+            State::Connecting { future } => State::Connecting { future },
+            State::Streaming { stream } => State::Streaming { stream },
+            // The pending backoff belongs to the old clock, so reconnect right away.
+            State::Sleeping { .. } => State::Start,
+            State::Done => State::Done,
+        };
+
+        Retrying {
+            factory: self.factory,
+            policy: self.policy,
+            retry_if: self.retry_if,
+            clock,
+            state,
+            attempt: self.attempt,
+            events: self.events,
+        }
+    }
+}
+
+impl<F, Fut, S, T, E, P, C> Stream for Retrying<F, Fut, S, E, P, C>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = S>,
+    S: Stream<Item = Result<T, E>>,
+    P: FnMut(&E) -> bool,
+    C: Clock,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        let mut slept = false;
+
+        loop {
+            let cause = match this.state.as_mut().project() {
+                StateProj::Start => {
+                    let future = (this.factory)();
+                    this.state.set(State::Connecting { future });
+                    continue;
+                }
+                StateProj::Connecting { future } => {
+                    let stream = ready!(future.poll(cx));
+                    if *this.attempt > 0 {
+                        send(this.events, RetryEvent::Reconnected { attempt: *this.attempt });
+                    }
+                    this.state.set(State::Streaming { stream });
+                    continue;
+                }
+                StateProj::Streaming { stream } => match ready!(stream.poll_next(cx)) {
+                    Some(Ok(item)) => {
+                        *this.attempt = 0;
+                        return Poll::Ready(Some(Ok(item)));
+                    }
+                    Some(Err(error)) if !(this.retry_if)(&error) => return Poll::Ready(Some(Err(error))),
+                    Some(Err(error)) => RetryCause::Error(error),
+                    None => RetryCause::Ended,
+                },
+                StateProj::Sleeping { sleep } => {
+                    ready!(Pin::new(sleep).poll(cx));
+                    this.state.set(State::Start);
+                    if slept {
+                        // Reconnect at most once per poll, so that a zero backoff and a stream
+                        // that ends right away cannot keep this poll from returning.
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending;
+                    }
+                    slept = true;
+                    continue;
+                }
+                StateProj::Done => return Poll::Ready(None),
+            };
+
+            *this.attempt += 1;
+            if this.policy.max_retries.is_some_and(|max_retries| *this.attempt > max_retries) {
+                send(this.events, RetryEvent::GaveUp { cause });
+                this.state.set(State::Done);
+                return Poll::Ready(None);
+            }
+
+            let delay = this.policy.backoff(*this.attempt);
+            send(
+                this.events,
+                RetryEvent::Reconnecting {
+                    attempt: *this.attempt,
+                    delay,
+                    cause,
+                },
+            );
+            let sleep = this.clock.sleep_until(this.clock.now() + delay);
+            this.state.set(State::Sleeping { sleep });
+        }
+    }
+}
+
+impl<F, Fut, S, T, E, P, C> FusedStream for Retrying<F, Fut, S, E, P, C>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = S>,
+    S: Stream<Item = Result<T, E>>,
+    P: FnMut(&E) -> bool,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+}
+
+impl<F, Fut, S, E, P, C> std::fmt::Debug for Retrying<F, Fut, S, E, P, C>
+where
+    C: Clock,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Retrying")
+            .field("policy", &self.policy)
+            .field("attempt", &self.attempt)
+            .finish_non_exhaustive()
+    }
+}
+
+fn send<E>(events: &Option<mpsc::UnboundedSender<RetryEvent<E>>>, event: RetryEvent<E>) {
+    if let Some(events) = events {
+        // The receiver may have been dropped, in which case nobody is interested.
+        let _ = events.unbounded_send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+
+    #[test]
+    fn test_retrying_reconnects_with_backoff() {
+        let clock = MockClock::new();
+        let mut connections = 0;
+        let mut stream = retrying(
+            move || {
+                connections += 1;
+                std::future::ready(futures::stream::iter(vec![Ok::<_, &str>(connections)]))
+            },
+            RetryPolicy::exponential(Duration::from_millis(10)),
+        )
+        .with_clock(clock.clone());
+        let mut events = stream.events();
+
+        assert_eq!(stream.next().now_or_never(), Some(Some(Ok(1))));
+        assert_eq!(stream.next().now_or_never(), None);
+        assert_eq!(
+            events.next().now_or_never(),
+            Some(Some(RetryEvent::Reconnecting {
+                attempt: 1,
+                delay: Duration::from_millis(10),
+                cause: RetryCause::Ended,
+            }))
+        );
+
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(Ok(2))));
+        assert_eq!(events.next().now_or_never(), Some(Some(RetryEvent::Reconnected { attempt: 1 })));
+    }
+
+    #[test]
+    fn test_retrying_backoff_grows_until_success() {
+        let clock = MockClock::new();
+        let mut connections = 0;
+        let mut stream = retrying(
+            move || {
+                connections += 1;
+                let items = if connections == 3 { vec![Ok(connections)] } else { vec![Err("refused")] };
+                std::future::ready(futures::stream::iter(items))
+            },
+            RetryPolicy::exponential(Duration::from_millis(10)),
+        )
+        .with_clock(clock.clone());
+        let mut events = stream.events();
+
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(Ok(3))));
+
+        let delays: Vec<_> = std::iter::from_fn(|| events.next().now_or_never().flatten())
+            .filter_map(|event| match event {
+                RetryEvent::Reconnecting { delay, cause, .. } => {
+                    assert_eq!(cause, RetryCause::Error("refused"));
+                    Some(delay)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(delays, vec![Duration::from_millis(10), Duration::from_millis(20)]);
+    }
+
+    #[test]
+    fn test_retrying_gives_up() {
+        let clock = MockClock::new();
+        let mut stream = retrying(
+            || std::future::ready(futures::stream::iter(vec![Err::<(), _>("refused")])),
+            RetryPolicy::exponential(Duration::from_millis(10)).max_retries(1),
+        )
+        .with_clock(clock.clone());
+        let mut events = stream.events();
+
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+        assert!(stream.is_terminated());
+
+        let last = std::iter::from_fn(|| events.next().now_or_never().flatten()).last();
+        assert_eq!(
+            last,
+            Some(RetryEvent::GaveUp {
+                cause: RetryCause::Error("refused")
+            })
+        );
+    }
+
+    #[test]
+    fn test_retrying_passes_non_fatal_errors_through() {
+        let stream = retrying(
+            || std::future::ready(futures::stream::iter(vec![Ok(1), Err("transient"), Ok(2), Err("fatal")])),
+            RetryPolicy::exponential(Duration::from_millis(10)).max_retries(0),
+        )
+        .retry_if(|error| *error == "fatal")
+        .with_clock(MockClock::new());
+
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(1), Err("transient"), Ok(2)]);
+    }
+
+    #[test]
+    fn test_retrying_zero_backoff_yields_between_reconnects() {
+        let mut stream = retrying(
+            || std::future::ready(futures::stream::empty::<Result<(), ()>>()),
+            RetryPolicy::exponential(Duration::ZERO).max_retries(100),
+        )
+        .with_clock(MockClock::new());
+        let counter = crate::testing::WakeCounter::new();
+        let waker = counter.waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(stream.poll_next_unpin(&mut cx), Poll::Pending);
+        assert_eq!(counter.count(), 1);
+
+        let mut polls = 1;
+        while stream.poll_next_unpin(&mut cx).is_pending() {
+            polls += 1;
+        }
+        assert!(polls > 1);
+        assert!(stream.is_terminated());
+    }
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy::exponential(Duration::from_secs(1)).max_backoff(Duration::from_secs(5));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(usize::MAX), Duration::from_secs(5));
+    }
+}
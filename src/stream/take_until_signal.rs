@@ -0,0 +1,192 @@
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU8;
+use std::sync::atomic::Ordering;
+use std::task::Context;
+use std::task::Poll;
+
+/// The signal future of [`StreamExt::take_until_signal`](crate::StreamExt::take_until_signal),
+/// which discards the output of the signal.
+pub type DiscardOutput<Fut, T> = futures::future::Map<Fut, fn(<Fut as Future>::Output) -> Option<T>>;
+
+const RUNNING: u8 = 0;
+const INTERRUPTED: u8 = 1;
+const COMPLETED: u8 = 2;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that yields the items of an inner stream until a signal future
+    /// resolves.
+    ///
+    /// When the signal resolves to `Some(item)`, that item is yielded as the final item.
+    #[derive(Debug)]
+    pub struct TakeUntilSignal<S, Fut> {
+        #[pin]
+        pub(crate) stream: S,
+        #[pin]
+        pub(crate) signal: Option<Fut>,
+        pub(crate) state: Arc<AtomicU8>,
+    }
+}
+
+impl<S, Fut> TakeUntilSignal<S, Fut> {
+    pub(crate) fn new(stream: S, signal: Fut) -> Self {
+        TakeUntilSignal {
+            stream,
+            signal: Some(signal),
+            state: Arc::new(AtomicU8::new(RUNNING)),
+        }
+    }
+
+    /// Returns a handle that reports how the stream ended.
+    ///
+    /// The handle stays valid after the stream has been moved or dropped.
+    pub fn handle(&self) -> TakeUntilHandle {
+        TakeUntilHandle {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<S, Fut> Stream for TakeUntilSignal<S, Fut>
+where
+    S: Stream,
+    Fut: Future<Output = Option<S::Item>>,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        if this.state.load(Ordering::Acquire) != RUNNING {
+            return Poll::Ready(None);
+        }
+
+        if let Some(signal) = this.signal.as_mut().as_pin_mut()
+            && let Poll::Ready(last) = signal.poll(cx)
+        {
+            this.signal.set(None);
+            this.state.store(INTERRUPTED, Ordering::Release);
+            return Poll::Ready(last);
+        }
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => Poll::Ready(Some(item)),
+            Poll::Ready(None) => {
+                this.signal.set(None);
+                this.state.store(COMPLETED, Ordering::Release);
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.state.load(Ordering::Acquire) != RUNNING {
+            return (0, Some(0));
+        }
+
+        let (_, upper) = self.stream.size_hint();
+        (0, upper.and_then(|upper| upper.checked_add(1)))
+    }
+}
+
+impl<S, Fut> FusedStream for TakeUntilSignal<S, Fut>
+where
+    S: Stream,
+    Fut: Future<Output = Option<S::Item>>,
+{
+    fn is_terminated(&self) -> bool {
+        self.state.load(Ordering::Acquire) != RUNNING
+    }
+}
+
+/// How a stream returned by [`StreamExt::take_until_signal`](crate::StreamExt::take_until_signal)
+/// ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TakeUntilStatus {
+    /// The stream has not ended yet.
+    Running,
+    /// The stream was cut short because the signal resolved.
+    Interrupted,
+    /// The inner stream ended on its own before the signal resolved.
+    Completed,
+}
+
+/// A handle that reports how a stream returned by
+/// [`StreamExt::take_until_signal`](crate::StreamExt::take_until_signal) ended.
+#[derive(Clone, Debug)]
+pub struct TakeUntilHandle {
+    state: Arc<AtomicU8>,
+}
+
+impl TakeUntilHandle {
+    /// Returns the current status of the stream.
+    pub fn status(&self) -> TakeUntilStatus {
+        match self.state.load(Ordering::Acquire) {
+            INTERRUPTED => TakeUntilStatus::Interrupted,
+            COMPLETED => TakeUntilStatus::Completed,
+            _ => TakeUntilStatus::Running,
+        }
+    }
+
+    /// Returns `true` if the stream was cut short because the signal resolved.
+    pub fn was_interrupted(&self) -> bool {
+        self.status() == TakeUntilStatus::Interrupted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::TakeUntilStatus;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+
+    #[test]
+    fn test_take_until_signal_completed() {
+        let stream = futures::stream::iter(vec![1, 2, 3]).take_until_signal(futures::future::pending::<()>());
+        let handle = stream.handle();
+        assert_eq!(handle.status(), TakeUntilStatus::Running);
+
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+        assert_eq!(handle.status(), TakeUntilStatus::Completed);
+        assert!(!handle.was_interrupted());
+    }
+
+    #[test]
+    fn test_take_until_signal_interrupted() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let (shutdown_tx, shutdown_rx) = futures::channel::oneshot::channel::<()>();
+        let mut stream = rx.take_until_signal(shutdown_rx);
+        let handle = stream.handle();
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        shutdown_tx.send(()).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(None));
+        assert!(futures::stream::FusedStream::is_terminated(&stream));
+        assert!(handle.was_interrupted());
+    }
+
+    #[test]
+    fn test_take_until_signal_then() {
+        let (shutdown_tx, shutdown_rx) = futures::channel::oneshot::channel::<()>();
+        let stream = futures::stream::pending::<&str>().take_until_signal_then(async {
+            shutdown_rx.await.ok();
+            Some("goodbye")
+        });
+        let handle = stream.handle();
+        shutdown_tx.send(()).unwrap();
+
+        let collected: Vec<_> = futures::executor::block_on_stream(Box::pin(stream)).collect();
+        assert_eq!(collected, vec!["goodbye"]);
+        assert!(handle.was_interrupted());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::timer::Clock;
+use crate::timer::MAX_ITEMS_PER_POLL;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that emits at most one item of an inner stream per interval.
+    ///
+    /// The first item of an interval is emitted immediately if the leading edge is enabled. The
+    /// latest item received during an interval is emitted when the interval ends if the trailing
+    /// edge is enabled. Other items are dropped.
+    #[derive(Debug)]
+    pub struct Throttle<S, C>
+    where
+        S: Stream,
+        C: Clock,
+    {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) clock: C,
+        pub(crate) interval: Duration,
+        pub(crate) leading: bool,
+        pub(crate) trailing: bool,
+        pub(crate) pending: Option<S::Item>,
+        pub(crate) window: Option<C::Sleep>,
+        pub(crate) stream_done: bool,
+    }
+}
+
+impl<S, C> Throttle<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    /// Sets whether the first item of an interval is emitted immediately. Enabled by default.
+    pub fn leading(mut self, leading: bool) -> Self {
+        self.leading = leading;
+        self
+    }
+
+    /// Sets whether the latest item received during an interval is emitted when the interval
+    /// ends. Enabled by default.
+    pub fn trailing(mut self, trailing: bool) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    /// Uses the given clock to measure time, e.g. a [`MockClock`](crate::timer::MockClock) in
+    /// tests.
+    ///
+    /// If an interval is already running, it restarts on the new clock.
+    pub fn with_clock<C2>(self, clock: C2) -> Throttle<S, C2>
+    where
+        C2: Clock,
+    {
+        let window = self.window.is_some().then(|| clock.sleep_until(clock.now() + self.interval));
+        Throttle {
+            stream: self.stream,
+            clock,
+            interval: self.interval,
+            leading: self.leading,
+            trailing: self.trailing,
+            pending: self.pending,
+            window,
+            stream_done: self.stream_done,
+        }
+    }
+}
+
+impl<S, C> Stream for Throttle<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let mut budget = MAX_ITEMS_PER_POLL;
+        while !*this.stream_done {
+            if budget == 0 {
+                // The inner stream may still be ready, so ask to be polled again right away.
+                cx.waker().wake_by_ref();
+                break;
+            }
+            budget -= 1;
+
+            match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    if this.window.is_none() {
+                        *this.window = Some(this.clock.sleep_until(this.clock.now() + *this.interval));
+                        if *this.leading {
+                            return Poll::Ready(Some(item));
+                        }
+                    }
+                    if *this.trailing {
+                        *this.pending = Some(item);
+                    }
+                }
+                Poll::Ready(None) => *this.stream_done = true,
+                Poll::Pending => break,
+            }
+        }
+
+        if let Some(window) = this.window.as_mut()
+            && Pin::new(window).poll(cx).is_ready()
+        {
+            *this.window = None;
+            if let Some(item) = this.pending.take() {
+                *this.window = Some(this.clock.sleep_until(this.clock.now() + *this.interval));
+                return Poll::Ready(Some(item));
+            }
+        }
+
+        if *this.stream_done && this.pending.is_none() {
+            *this.window = None;
+            return Poll::Ready(None);
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<S, C> FusedStream for Throttle<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream_done && self.pending.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::testing::WakeCounter;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+    use std::time::Duration;
+
+    #[test]
+    fn test_throttle_leading_and_trailing() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.throttle(Duration::from_millis(10)).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+
+        // The trailing item starts a new interval.
+        tx.unbounded_send(4).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(4)));
+
+        // A quiet interval ends without emitting, so the next item is a leading one.
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), None);
+        tx.unbounded_send(5).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(5)));
+
+        drop(tx);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn test_throttle_leading_only() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.throttle(Duration::from_millis(10)).trailing(false).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        tx.unbounded_send(3).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+    }
+
+    #[test]
+    fn test_throttle_trailing_only() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.throttle(Duration::from_millis(10)).leading(false).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(2)));
+    }
+
+    #[test]
+    fn test_throttle_waits_for_interval_at_end() {
+        let clock = MockClock::new();
+        let mut stream = futures::stream::iter(vec![1, 2, 3])
+            .throttle(Duration::from_millis(10))
+            .with_clock(clock.clone());
+
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(3)));
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn test_throttle_with_clock_rearms_interval() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.throttle(Duration::from_millis(10));
+        tx.unbounded_send(1).unwrap();
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(1)));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        let clock = MockClock::new();
+        let mut stream = stream.with_clock(clock.clone());
+        drop(tx);
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        assert_eq!(stream.next().now_or_never(), Some(Some(2)));
+    }
+
+    #[test]
+    fn test_throttle_always_ready_yields() {
+        let clock = MockClock::new();
+        let mut stream = futures::stream::iter(0..).throttle(Duration::from_millis(10)).with_clock(clock.clone());
+        let counter = WakeCounter::new();
+        let waker = counter.waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+
+        assert_eq!(stream.poll_next_unpin(&mut cx), std::task::Poll::Ready(Some(0)));
+        assert_eq!(stream.poll_next_unpin(&mut cx), std::task::Poll::Pending);
+        assert_eq!(counter.count(), 1);
+
+        // The trailing edge still fires while the inner stream keeps producing.
+        clock.advance(Duration::from_millis(10));
+        match stream.poll_next_unpin(&mut cx) {
+            std::task::Poll::Ready(Some(item)) => assert!(item > 1),
+            other => panic!("expected the trailing item, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::timer::Clock;
+use crate::timer::Elapsed;
+use futures::Stream;
+use futures::stream::FusedStream;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Duration;
+
+pin_project_lite::pin_project! {
+    /// A stream adapter that yields an [`Elapsed`] error whenever the next item of an inner
+    /// stream does not arrive within a duration.
+    #[derive(Debug)]
+    pub struct TimeoutEach<S, C>
+    where
+        C: Clock,
+    {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) clock: C,
+        pub(crate) duration: Duration,
+        pub(crate) sleep: Option<C::Sleep>,
+    }
+}
+
+impl<S, C> TimeoutEach<S, C>
+where
+    C: Clock,
+{
+    /// Uses the given clock to measure time, e.g. a [`MockClock`](crate::timer::MockClock) in
+    /// tests.
+    ///
+    /// A deadline that is already running restarts on the new clock.
+    pub fn with_clock<C2>(self, clock: C2) -> TimeoutEach<S, C2>
+    where
+        C2: Clock,
+    {
+        // The deadline is armed again on the next poll.
+        TimeoutEach {
+            stream: self.stream,
+            clock,
+            duration: self.duration,
+            sleep: None,
+        }
+    }
+}
+
+impl<S, C> Stream for TimeoutEach<S, C>
+where
+    S: Stream,
+    C: Clock,
+{
+    type Item = Result<S::Item, Elapsed>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => {
+                *this.sleep = None;
+                return Poll::Ready(Some(Ok(item)));
+            }
+            Poll::Ready(None) => {
+                *this.sleep = None;
+                return Poll::Ready(None);
+            }
+            Poll::Pending => {}
+        }
+
+        let duration = *this.duration;
+        let clock = &*this.clock;
+        let sleep = this.sleep.get_or_insert_with(|| clock.sleep_until(clock.now() + duration));
+        match Pin::new(sleep).poll(cx) {
+            Poll::Ready(()) => {
+                // Start a new deadline for the item after this one.
+                *this.sleep = None;
+                Poll::Ready(Some(Err(Elapsed { duration })))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.stream.size_hint().0, None)
+    }
+}
+
+impl<S, C> FusedStream for TimeoutEach<S, C>
+where
+    S: FusedStream,
+    C: Clock,
+{
+    fn is_terminated(&self) -> bool {
+        self.stream.is_terminated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::StreamExt as _;
+    use crate::timer::MockClock;
+    use futures::FutureExt as _;
+    use futures::StreamExt as _;
+    use std::time::Duration;
+
+    #[test]
+    fn test_timeout_each_ready() {
+        let stream = futures::stream::iter(vec![1, 2])
+            .timeout_each(Duration::from_millis(10))
+            .with_clock(MockClock::new());
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![Ok(1), Ok(2)]);
+    }
+
+    #[test]
+    fn test_timeout_each_elapsed() {
+        let clock = MockClock::new();
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = rx.timeout_each(Duration::from_millis(20)).with_clock(clock.clone());
+
+        tx.unbounded_send(1).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(Ok(1))));
+        assert_eq!(stream.next().now_or_never(), None);
+
+        clock.advance(Duration::from_millis(20));
+        let err = stream.next().now_or_never().unwrap().unwrap().unwrap_err();
+        assert_eq!(err.duration(), Duration::from_millis(20));
+        assert_eq!(err.to_string(), "deadline elapsed after 20ms");
+
+        // The deadline restarted after the error.
+        assert_eq!(stream.next().now_or_never(), None);
+        clock.advance(Duration::from_millis(10));
+        tx.unbounded_send(2).unwrap();
+        assert_eq!(stream.next().now_or_never(), Some(Some(Ok(2))));
+
+        drop(tx);
+        assert_eq!(stream.next().now_or_never(), Some(None));
+    }
+}
@@ -0,0 +1,328 @@
+//! Deterministic test doubles for testing stream adapters.
+//!
+//! A [`ScriptedStream`] follows a script of [`Step`]s, so tests can control exactly when the
+//! stream is ready, pending, or wakes its task, and can then assert how an adapter propagates
+//! those. A [`WakeCounter`] provides a waker that counts how often it is woken.
+//!
+//! # Examples
+//!
+//! ```
+//! use futures::Stream;
+//! use std::pin::Pin;
+//! use std::task::Context;
+//! use std::task::Poll;
+//! use type_toppings::StreamExt as _;
+//! use type_toppings::testing::ScriptedStream;
+//! use type_toppings::testing::Step;
+//! use type_toppings::testing::WakeCounter;
+//!
+//! let inner = ScriptedStream::new([Step::Ready(1u32), Step::WakeAfter(1), Step::Ready(2)]);
+//! let script = inner.handle();
+//! let mut stream = inner.map_into::<u64>();
+//!
+//! let counter = WakeCounter::new();
+//! let waker = counter.waker();
+//! let mut cx = Context::from_waker(&waker);
+//!
+//! assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(1)));
+//! assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Pending);
+//!
+//! script.advance();
+//! assert_eq!(counter.count(), 1);
+//! assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(Some(2)));
+//! assert_eq!(Pin::new(&mut stream).poll_next(&mut cx), Poll::Ready(None));
+//! assert_eq!(script.polls(), 4);
+//! ```
+
+use futures::Stream;
+use futures::stream::FusedStream;
+use futures::task::ArcWake;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+
+/// A step in the script of a [`ScriptedStream`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Step<T> {
+    /// Yield the item.
+    Ready(T),
+    /// Return [`Poll::Pending`] without ever waking the task.
+    Pending,
+    /// Return [`Poll::Pending`], and wake the task once [`ScriptHandle::advance`] has been
+    /// called `n` times. With `WakeAfter(0)`, the task is woken before returning.
+    WakeAfter(usize),
+}
+
+/// A stream that follows a script of [`Step`]s, one step per poll, and ends once the script
+/// is exhausted.
+///
+/// Every poll and every wake-up is recorded, and can be inspected through a [`ScriptHandle`].
+#[derive(Debug)]
+pub struct ScriptedStream<T> {
+    steps: VecDeque<Step<T>>,
+    state: Arc<Mutex<ScriptState>>,
+    terminated: bool,
+}
+
+#[derive(Debug, Default)]
+struct ScriptState {
+    polls: usize,
+    wakes: usize,
+    pending_wake: Option<(usize, Waker)>,
+}
+
+impl<T> ScriptedStream<T> {
+    /// Creates a stream that follows the given script.
+    pub fn new(steps: impl IntoIterator<Item = Step<T>>) -> Self {
+        ScriptedStream {
+            steps: steps.into_iter().collect(),
+            state: Arc::default(),
+            terminated: false,
+        }
+    }
+
+    /// Returns a handle to drive the wake-ups of the script and inspect the recorded polls.
+    pub fn handle(&self) -> ScriptHandle {
+        ScriptHandle {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl<T> Stream for ScriptedStream<T>
+where
+    T: Unpin,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut state = lock(&this.state);
+        state.polls += 1;
+
+        match this.steps.pop_front() {
+            Some(Step::Ready(item)) => Poll::Ready(Some(item)),
+            Some(Step::Pending) => Poll::Pending,
+            Some(Step::WakeAfter(0)) => {
+                state.wakes += 1;
+                // Wake without holding the lock, as the waker may poll the stream right away.
+                drop(state);
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            Some(Step::WakeAfter(n)) => {
+                state.pending_wake = Some((n, cx.waker().clone()));
+                Poll::Pending
+            }
+            None => {
+                this.terminated = true;
+                Poll::Ready(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.steps.iter().filter(|step| matches!(step, Step::Ready(_))).count();
+        (0, Some(remaining))
+    }
+}
+
+impl<T> FusedStream for ScriptedStream<T>
+where
+    T: Unpin,
+{
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+/// A handle to a [`ScriptedStream`], obtained with [`ScriptedStream::handle`].
+///
+/// Clones share the same script.
+#[derive(Clone, Debug)]
+pub struct ScriptHandle {
+    state: Arc<Mutex<ScriptState>>,
+}
+
+impl ScriptHandle {
+    /// Advances the script towards the pending [`Step::WakeAfter`] wake-up, if any, waking the
+    /// task once it is due.
+    pub fn advance(&self) {
+        let waker = {
+            let mut state = lock(&self.state);
+            match state.pending_wake.take() {
+                Some((n, waker)) if n <= 1 => {
+                    state.wakes += 1;
+                    Some(waker)
+                }
+                Some((n, waker)) => {
+                    state.pending_wake = Some((n - 1, waker));
+                    None
+                }
+                None => None,
+            }
+        };
+
+        // Wake without holding the lock, as the waker may poll the stream or use this handle.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /// Returns the number of times the stream has been polled.
+    pub fn polls(&self) -> usize {
+        lock(&self.state).polls
+    }
+
+    /// Returns the number of times the stream has woken its task.
+    pub fn wakes(&self) -> usize {
+        lock(&self.state).wakes
+    }
+
+    /// Returns whether a [`Step::WakeAfter`] wake-up is still waiting for [`ScriptHandle::advance`].
+    pub fn has_pending_wake(&self) -> bool {
+        lock(&self.state).pending_wake.is_some()
+    }
+}
+
+fn lock(state: &Mutex<ScriptState>) -> MutexGuard<'_, ScriptState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A source of wakers that count how many times they are woken.
+///
+/// Clones share the same count.
+#[derive(Clone, Debug, Default)]
+pub struct WakeCounter {
+    count: Arc<Counter>,
+}
+
+#[derive(Debug, Default)]
+struct Counter(AtomicUsize);
+
+impl ArcWake for Counter {
+    fn wake_by_ref(arc_self: &Arc<Self>) {
+        arc_self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl WakeCounter {
+    /// Creates a new counter, starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a waker that increments the count when woken.
+    pub fn waker(&self) -> Waker {
+        futures::task::waker(Arc::clone(&self.count))
+    }
+
+    /// Returns the number of times the wakers of this counter have been woken.
+    pub fn count(&self) -> usize {
+        self.count.0.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll_next<S: Stream + Unpin>(stream: &mut S, counter: &WakeCounter) -> Poll<Option<S::Item>> {
+        let waker = counter.waker();
+        Pin::new(stream).poll_next(&mut Context::from_waker(&waker))
+    }
+
+    #[test]
+    fn test_scripted_stream_follows_script() {
+        let mut stream = ScriptedStream::new([Step::Ready(1), Step::Pending, Step::Ready(2)]);
+        let script = stream.handle();
+        let counter = WakeCounter::new();
+
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(Some(1)));
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Pending);
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(Some(2)));
+        assert!(!stream.is_terminated());
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(None));
+        assert!(stream.is_terminated());
+
+        assert_eq!(script.polls(), 4);
+        assert_eq!(script.wakes(), 0);
+        assert_eq!(counter.count(), 0);
+    }
+
+    #[test]
+    fn test_scripted_stream_wake_after() {
+        let mut stream = ScriptedStream::new([Step::WakeAfter(2), Step::WakeAfter(0), Step::Ready(1)]);
+        let script = stream.handle();
+        let counter = WakeCounter::new();
+
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Pending);
+        assert!(script.has_pending_wake());
+        script.advance();
+        assert_eq!(counter.count(), 0);
+        script.advance();
+        assert_eq!(counter.count(), 1);
+        assert!(!script.has_pending_wake());
+
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Pending);
+        assert_eq!(counter.count(), 2);
+        assert_eq!(script.wakes(), 2);
+
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(Some(1)));
+    }
+
+    #[test]
+    fn test_scripted_stream_pending_through_adapter() {
+        use crate::StreamExt as _;
+
+        let inner = ScriptedStream::new([Step::WakeAfter(1), Step::Ready(1u8)]);
+        let script = inner.handle();
+        let mut stream = inner.map_into::<u32>();
+        let counter = WakeCounter::new();
+
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Pending);
+        script.advance();
+        assert_eq!(counter.count(), 1);
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(Some(1)));
+        assert_eq!(poll_next(&mut stream, &counter), Poll::Ready(None));
+        assert_eq!(script.polls(), 3);
+    }
+
+    #[test]
+    fn test_scripted_stream_waker_may_use_handle() {
+        let mut stream = ScriptedStream::new([Step::WakeAfter(1)]);
+        let script = stream.handle();
+        let waker = futures::task::waker(Arc::new(HandleWaker(stream.handle())));
+
+        assert_eq!(
+            Pin::new(&mut stream).poll_next(&mut Context::from_waker(&waker)),
+            Poll::<Option<()>>::Pending
+        );
+        script.advance();
+        assert_eq!(script.wakes(), 1);
+    }
+
+    /// A waker that inspects the script when woken.
+    struct HandleWaker(ScriptHandle);
+
+    impl ArcWake for HandleWaker {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            assert_eq!(arc_self.0.wakes(), 1);
+        }
+    }
+
+    #[test]
+    fn test_scripted_stream_block_on() {
+        let stream = ScriptedStream::new([Step::Ready(1), Step::WakeAfter(0), Step::Ready(2)]);
+        let collected: Vec<_> = futures::executor::block_on_stream(stream).collect();
+        assert_eq!(collected, vec![1, 2]);
+    }
+}
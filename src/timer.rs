@@ -0,0 +1,264 @@
+//! Runtime-agnostic timer support for the time-based extensions.
+//!
+//! Timers are driven by [`futures_timer`], which does not depend on any particular async
+//! runtime, so the time-based extensions work with any executor, including
+//! [`futures::executor`].
+//!
+//! Adapters that accept a [`Clock`] can be driven by a [`MockClock`] in tests.
+//!
+//! Streams that recreate their source with exponential backoff are created with
+//! [`stream::retrying`](crate::stream::retrying).
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::task::Waker;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The maximum number of items that a time-based adapter pulls from an always-ready inner stream
+/// in a single poll, before yielding to the executor so that other tasks, and its own timers, get
+/// a turn.
+pub(crate) const MAX_ITEMS_PER_POLL: usize = 32;
+
+/// Error returned when a deadline has elapsed before an item or output became available.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elapsed {
+    pub(crate) duration: Duration,
+}
+
+impl Elapsed {
+    /// Returns the duration that elapsed.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deadline elapsed after {:?}", self.duration)
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+/// A source of time for the time-based extensions.
+///
+/// [`SystemClock`] is used by default. [`MockClock`] can be used instead to control the passing
+/// of time in tests, without sleeping.
+pub trait Clock {
+    /// The future returned by [`Clock::sleep_until`].
+    type Sleep: Future<Output = ()> + Unpin;
+
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+
+    /// Returns a future that completes once `deadline` has been reached.
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep;
+}
+
+/// The system clock, with sleeps driven by [`futures_timer`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    type Sleep = futures_timer::Delay;
+
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        futures_timer::Delay::new(deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+/// A clock that only moves forward when advanced manually, for deterministic tests.
+///
+/// Clones share the same time.
+///
+/// # Examples
+///
+/// ```
+/// use futures::FutureExt as _;
+/// use std::time::Duration;
+/// use type_toppings::timer::Clock as _;
+/// use type_toppings::timer::MockClock;
+///
+/// let clock = MockClock::new();
+/// let start = clock.now();
+///
+/// let mut sleep = clock.sleep_until(start + Duration::from_secs(10));
+/// assert_eq!((&mut sleep).now_or_never(), None);
+///
+/// clock.advance(Duration::from_secs(10));
+/// assert_eq!(clock.now(), start + Duration::from_secs(10));
+/// assert_eq!((&mut sleep).now_or_never(), Some(()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    state: Arc<Mutex<MockClockState>>,
+}
+
+#[derive(Debug)]
+struct MockClockState {
+    now: Instant,
+    next_sleep_id: u64,
+    sleepers: Vec<Sleeper>,
+}
+
+/// A pending [`MockSleep`], registered to be woken once its deadline has been reached.
+#[derive(Debug)]
+struct Sleeper {
+    id: u64,
+    deadline: Instant,
+    waker: Waker,
+}
+
+impl MockClock {
+    /// Creates a new mock clock, starting at the current instant.
+    pub fn new() -> Self {
+        MockClock {
+            state: Arc::new(Mutex::new(MockClockState {
+                now: Instant::now(),
+                next_sleep_id: 0,
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Moves the clock forward by `duration`, waking the sleeps whose deadline has been reached.
+    pub fn advance(&self, duration: Duration) {
+        let wakers = {
+            let mut state = self.lock();
+            state.now += duration;
+            let now = state.now;
+            let (ready, pending) = std::mem::take(&mut state.sleepers)
+                .into_iter()
+                .partition::<Vec<_>, _>(|sleeper| sleeper.deadline <= now);
+            state.sleepers = pending;
+            ready
+        };
+
+        for sleeper in wakers {
+            sleeper.waker.wake();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockClockState> {
+        self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    type Sleep = MockSleep;
+
+    fn now(&self) -> Instant {
+        self.lock().now
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        let id = {
+            let mut state = self.lock();
+            state.next_sleep_id += 1;
+            state.next_sleep_id
+        };
+        MockSleep {
+            clock: self.clone(),
+            id,
+            deadline,
+        }
+    }
+}
+
+/// The future returned by [`MockClock::sleep_until`](Clock::sleep_until).
+#[derive(Debug)]
+pub struct MockSleep {
+    clock: MockClock,
+    id: u64,
+    deadline: Instant,
+}
+
+impl Future for MockSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.clock.lock();
+        if state.now >= self.deadline {
+            return Poll::Ready(());
+        }
+
+        // Polling again only replaces the waker, so a sleep is registered at most once.
+        match state.sleepers.iter_mut().find(|sleeper| sleeper.id == self.id) {
+            Some(sleeper) => sleeper.waker.clone_from(cx.waker()),
+            None => state.sleepers.push(Sleeper {
+                id: self.id,
+                deadline: self.deadline,
+                waker: cx.waker().clone(),
+            }),
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for MockSleep {
+    fn drop(&mut self) {
+        self.clock.lock().sleepers.retain(|sleeper| sleeper.id != self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::WakeCounter;
+
+    fn registered(clock: &MockClock) -> usize {
+        clock.lock().sleepers.len()
+    }
+
+    #[test]
+    fn test_mock_sleep_registers_once() {
+        let clock = MockClock::new();
+        let counter = WakeCounter::new();
+        let waker = counter.waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut sleep = clock.sleep_until(clock.now() + Duration::from_secs(1));
+        for _ in 0..3 {
+            assert_eq!(Pin::new(&mut sleep).poll(&mut cx), Poll::Pending);
+        }
+        assert_eq!(registered(&clock), 1);
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(counter.count(), 1);
+        assert_eq!(registered(&clock), 0);
+        assert_eq!(Pin::new(&mut sleep).poll(&mut cx), Poll::Ready(()));
+    }
+
+    #[test]
+    fn test_mock_sleep_unregisters_on_drop() {
+        let clock = MockClock::new();
+        let counter = WakeCounter::new();
+        let waker = counter.waker();
+        let mut cx = Context::from_waker(&waker);
+
+        let mut sleep = clock.sleep_until(clock.now() + Duration::from_secs(1));
+        assert_eq!(Pin::new(&mut sleep).poll(&mut cx), Poll::Pending);
+        assert_eq!(registered(&clock), 1);
+
+        drop(sleep);
+        assert_eq!(registered(&clock), 0);
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(counter.count(), 0);
+    }
+}